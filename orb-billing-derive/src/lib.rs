@@ -0,0 +1,146 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Derive macros for the [`orb-billing`] crate.
+//!
+//! This crate is an implementation detail of `orb-billing`. Enable the
+//! `derive` feature of `orb-billing` rather than depending on it directly.
+//!
+//! [`orb-billing`]: https://docs.rs/orb-billing
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+/// Derives `orb_billing::OrbEvent` for a struct with named fields.
+///
+/// See the documentation of `orb_billing::OrbEvent` for details.
+#[proc_macro_derive(OrbEvent, attributes(orb))]
+pub fn derive_orb_event(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_orb_event(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_orb_event(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let mut event_name = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("orb") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("event_name") {
+                event_name = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported orb attribute"))
+            }
+        })?;
+    }
+    let event_name = event_name.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "missing `#[orb(event_name = \"...\")]` attribute",
+        )
+    })?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "OrbEvent can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "OrbEvent can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut to_properties = vec![];
+    let mut from_properties = vec![];
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let mut name = LitStr::new(&ident.to_string(), ident.span());
+        for attr in &field.attrs {
+            if !attr.path().is_ident("orb") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = meta.value()?.parse::<LitStr>()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported orb attribute"))
+                }
+            })?;
+        }
+        let ty = &field.ty;
+        to_properties.push(quote! {
+            if let ::std::option::Option::Some(value) =
+                <#ty as ::orb_billing::EventProperty>::to_property_value(&self.#ident)
+            {
+                properties.insert(::std::string::String::from(#name), value);
+            }
+        });
+        from_properties.push(quote::quote_spanned! {field.span()=>
+            #ident: <#ty as ::orb_billing::EventProperty>::from_property_value(
+                properties.get(#name),
+            )
+            .ok_or_else(|| ::orb_billing::Error::UnexpectedResponse {
+                detail: ::std::format!(
+                    "event property {:?} is missing or has an unexpected type",
+                    #name,
+                ),
+            })?,
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::orb_billing::OrbEvent for #ident #ty_generics #where_clause {
+            const EVENT_NAME: &'static str = #event_name;
+
+            fn to_properties(
+                &self,
+            ) -> ::std::collections::BTreeMap<::std::string::String, ::orb_billing::EventPropertyValue>
+            {
+                let mut properties = ::std::collections::BTreeMap::new();
+                #(#to_properties)*
+                properties
+            }
+
+            fn from_properties(
+                properties: &::std::collections::BTreeMap<
+                    ::std::string::String,
+                    ::orb_billing::EventPropertyValue,
+                >,
+            ) -> ::std::result::Result<Self, ::orb_billing::Error> {
+                ::std::result::Result::Ok(#ident {
+                    #(#from_properties)*
+                })
+            }
+        }
+    })
+}
@@ -23,11 +23,18 @@ use crate::config::ListParams;
 use crate::error::ApiError;
 use crate::{ClientBuilder, ClientConfig, Error};
 
+pub mod catalog;
+pub mod coupons;
+pub mod credit_notes;
 pub mod customers;
+pub mod discounts;
 pub mod events;
 pub mod invoices;
+pub mod items;
 pub mod marketplaces;
+pub mod metrics;
 pub mod plans;
+pub mod prices;
 pub mod subscriptions;
 pub mod taxes;
 
@@ -118,10 +125,13 @@ impl Client {
         #[derive(Deserialize)]
         struct Paginated<T> {
             data: Vec<T>,
+            // Some endpoints omit the pagination metadata when all results
+            // fit in a single page.
+            #[serde(default)]
             pagination_metadata: PaginationMetadata,
         }
 
-        #[derive(Deserialize)]
+        #[derive(Default, Deserialize)]
         struct PaginationMetadata {
             next_cursor: Option<String>,
         }
@@ -0,0 +1,779 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use codes_iso_4217::CurrencyCode;
+use futures_util::stream::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::client::items::{CreateItemRequest, Item};
+use crate::client::metrics::{
+    BillableMetric, CreateBillableMetricRequest, UpdateBillableMetricRequest,
+};
+use crate::client::plans::{
+    CreatePlanPriceRequest, CreatePlanRequest, Plan, PlanListParams, PlanStatus, UpdatePlanRequest,
+};
+use crate::client::prices::{CreatePriceRequest, Price, PriceCadence, PriceModelConfig};
+use crate::client::Client;
+use crate::config::ListParams;
+use crate::error::Error;
+use crate::money::Amount;
+
+/// A declarative description of the items, prices and plans in an Orb
+/// account.
+///
+/// A catalog is typically deserialized from a file that is checked into
+/// version control, e.g.:
+///
+/// ```yaml
+/// items:
+///   - name: Compute
+/// metrics:
+///   - name: Compute hours
+///     item: Compute
+///     sql: SELECT sum(hours) FROM events WHERE event_name = 'compute'
+/// plans:
+///   - external_id: standard
+///     name: Standard
+///     currency: USD
+///     prices:
+///       - name: Compute hours
+///         item: Compute
+///         cadence: monthly
+///         billable_metric: Compute hours
+///         model_type: unit
+///         unit_config:
+///           unit_amount: "1.50"
+/// ```
+///
+/// Use [`Client::diff_catalog`] to compute the changes required to bring the
+/// account in line with the catalog, and [`Client::apply_catalog_changeset`]
+/// to apply them.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Catalog {
+    /// The items in the catalog.
+    #[serde(default)]
+    pub items: Vec<CatalogItem>,
+    /// The billable metrics in the catalog.
+    #[serde(default)]
+    pub metrics: Vec<CatalogMetric>,
+    /// Prices that exist independently of any plan.
+    #[serde(default)]
+    pub prices: Vec<CatalogPrice>,
+    /// The plans in the catalog.
+    #[serde(default)]
+    pub plans: Vec<CatalogPlan>,
+    /// Whether to archive active plans that have an external ID but do not
+    /// appear in the catalog.
+    ///
+    /// Plans without an external ID are never archived.
+    #[serde(default)]
+    pub prune: bool,
+}
+
+/// An item in a [`Catalog`].
+///
+/// Items are identified by name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CatalogItem {
+    /// The name of the item.
+    pub name: String,
+}
+
+/// A billable metric in a [`Catalog`].
+///
+/// Billable metrics are identified by name.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CatalogMetric {
+    /// The name of the billable metric.
+    pub name: String,
+    /// A description of the billable metric.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The name of the item that the billable metric measures.
+    pub item: String,
+    /// The SQL query that defines the billable metric.
+    ///
+    /// Orb does not report the SQL query of existing billable metrics, so
+    /// the query is used only when the billable metric is created.
+    pub sql: String,
+    /// Arbitrary metadata attached to the billable metric.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// A price in a [`Catalog`] that exists independently of any plan.
+///
+/// Prices are identified by external ID.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CatalogPrice {
+    /// The external ID of the price.
+    pub external_id: String,
+    /// The currency of the price.
+    pub currency: CurrencyCode,
+    /// The specification of the price.
+    #[serde(flatten)]
+    pub spec: CatalogPriceSpec,
+}
+
+/// A plan in a [`Catalog`].
+///
+/// Plans are identified by external ID.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CatalogPlan {
+    /// The external ID of the plan.
+    pub external_id: String,
+    /// The name of the plan.
+    pub name: String,
+    /// The currency in which the plan's prices are denominated.
+    pub currency: CurrencyCode,
+    /// The prices billed by the plan.
+    pub prices: Vec<CatalogPriceSpec>,
+    /// Arbitrary metadata attached to the plan.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    /// Determines the difference between the invoice issue date and the
+    /// date that they are due.
+    #[serde(default)]
+    pub net_terms: Option<i64>,
+    /// Determines the default memo on the plan's invoices.
+    #[serde(default)]
+    pub default_invoice_memo: Option<String>,
+}
+
+/// The specification of a price in a [`Catalog`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CatalogPriceSpec {
+    /// The name of the price.
+    pub name: String,
+    /// The name of the item being priced.
+    pub item: String,
+    /// The cadence at which the price is billed.
+    pub cadence: PriceCadence,
+    /// The pricing model and its configuration.
+    #[serde(flatten)]
+    pub model: PriceModelConfig,
+    /// The name of the billable metric that usage prices are computed from.
+    #[serde(default)]
+    pub billable_metric: Option<String>,
+    /// The quantity billed for fixed prices.
+    #[serde(default, with = "crate::money::number::option")]
+    pub fixed_price_quantity: Option<Amount>,
+}
+
+impl CatalogPriceSpec {
+    /// Returns a canonical representation of the price's specification
+    /// that is insensitive to formatting differences in amounts.
+    fn canonicalize(&self) -> serde_json::Value {
+        let mut value = json!({
+            "name": self.name,
+            "item": self.item,
+            "cadence": self.cadence.to_string(),
+            "model": self.model,
+            "billable_metric": self.billable_metric,
+            "fixed_price_quantity": self.fixed_price_quantity,
+        });
+        canonicalize_amounts(&mut value);
+        value
+    }
+
+    /// Converts an existing price into a specification, if its model is
+    /// known.
+    ///
+    /// `metric_names` maps billable metric IDs to names.
+    fn from_price(price: &Price, metric_names: &BTreeMap<&str, &str>) -> Option<CatalogPriceSpec> {
+        let base = price.base()?;
+        Some(CatalogPriceSpec {
+            name: base.name.clone().unwrap_or_default(),
+            item: base.item.name.clone(),
+            cadence: base.cadence.clone()?,
+            model: price.model_config()?,
+            billable_metric: base.billable_metric.as_ref().map(|m| {
+                metric_names
+                    .get(&*m.id)
+                    .map_or_else(|| m.id.clone(), |name| name.to_string())
+            }),
+            fixed_price_quantity: base.fixed_price_quantity,
+        })
+    }
+}
+
+/// Rewrites decimal strings such that, e.g., `"1.50"` and `"1.5"` compare
+/// equal.
+fn canonicalize_amounts(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::String(s) => {
+            let is_decimal = !s.is_empty()
+                && s.trim_start_matches('-')
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == '.')
+                && s.matches('.').count() <= 1;
+            if is_decimal && s.contains('.') {
+                let trimmed = s.trim_end_matches('0').trim_end_matches('.');
+                *s = trimmed.to_string();
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(canonicalize_amounts),
+        serde_json::Value::Object(map) => map.values_mut().for_each(canonicalize_amounts),
+        _ => (),
+    }
+}
+
+/// A change required to bring an Orb account in line with a [`Catalog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogChange {
+    /// An item must be created.
+    CreateItem(CatalogItem),
+    /// A billable metric must be created.
+    CreateMetric(CatalogMetric),
+    /// A price must be created.
+    CreatePrice(CatalogPrice),
+    /// A plan must be created.
+    CreatePlan(CatalogPlan),
+    /// The metadata of an existing billable metric must be updated.
+    UpdateMetricMetadata {
+        /// The Orb ID of the billable metric.
+        id: String,
+        /// The name of the billable metric.
+        name: String,
+        /// The metadata keys to set, or to remove if mapped to `None`.
+        metadata: BTreeMap<String, Option<String>>,
+    },
+    /// The metadata of an existing plan must be updated.
+    UpdatePlanMetadata {
+        /// The Orb ID of the plan.
+        id: String,
+        /// The external ID of the plan.
+        external_id: String,
+        /// The metadata keys to set, or to remove if mapped to `None`.
+        metadata: BTreeMap<String, Option<String>>,
+    },
+    /// A plan that is no longer in the catalog must be archived.
+    ///
+    /// Orb's API does not support archiving plans, so these changes are
+    /// not applied by [`Client::apply_catalog_changeset`] and must instead
+    /// be performed in the Orb dashboard.
+    ArchivePlan {
+        /// The Orb ID of the plan.
+        id: String,
+        /// The external ID of the plan.
+        external_id: String,
+    },
+    /// An existing object differs from the catalog in a way that Orb does
+    /// not permit to be changed in place.
+    ///
+    /// Conflicts must be resolved manually, typically by giving the object
+    /// a new external ID in the catalog.
+    Conflict {
+        /// The kind of the conflicting object.
+        kind: &'static str,
+        /// The external ID of the conflicting object.
+        external_id: String,
+        /// A description of the conflict.
+        detail: String,
+    },
+}
+
+impl fmt::Display for CatalogChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CatalogChange::CreateItem(item) => write!(f, "+ create item {:?}", item.name),
+            CatalogChange::CreateMetric(metric) => {
+                write!(f, "+ create metric {:?}", metric.name)
+            }
+            CatalogChange::CreatePrice(price) => write!(
+                f,
+                "+ create price {:?} ({:?}, {})",
+                price.external_id,
+                price.spec.name,
+                price.currency.alpha_code()
+            ),
+            CatalogChange::CreatePlan(plan) => write!(
+                f,
+                "+ create plan {:?} ({:?}, {} prices)",
+                plan.external_id,
+                plan.name,
+                plan.prices.len()
+            ),
+            CatalogChange::UpdateMetricMetadata { name, metadata, .. } => {
+                write!(f, "~ update metric {name:?} metadata:")?;
+                fmt_metadata_changes(f, metadata)
+            }
+            CatalogChange::UpdatePlanMetadata {
+                external_id,
+                metadata,
+                ..
+            } => {
+                write!(f, "~ update plan {external_id:?} metadata:")?;
+                fmt_metadata_changes(f, metadata)
+            }
+            CatalogChange::ArchivePlan { external_id, .. } => {
+                write!(f, "- archive plan {external_id:?} (manual)")
+            }
+            CatalogChange::Conflict {
+                kind,
+                external_id,
+                detail,
+            } => write!(f, "! conflict in {kind} {external_id:?}: {detail}"),
+        }
+    }
+}
+
+/// Writes the metadata changes of an update to `f`.
+fn fmt_metadata_changes(
+    f: &mut fmt::Formatter,
+    metadata: &BTreeMap<String, Option<String>>,
+) -> fmt::Result {
+    for (key, value) in metadata {
+        match value {
+            Some(value) => write!(f, " {key}={value:?}")?,
+            None => write!(f, " -{key}")?,
+        }
+    }
+    Ok(())
+}
+
+/// The set of changes required to bring an Orb account in line with a
+/// [`Catalog`], as computed by [`Client::diff_catalog`].
+///
+/// The changeset's [`Display`](fmt::Display) implementation renders a
+/// human-readable summary of the changes, one per line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogChangeset {
+    /// The changes, in the order in which they will be applied.
+    pub changes: Vec<CatalogChange>,
+    item_ids: BTreeMap<String, String>,
+    metric_ids: BTreeMap<String, String>,
+}
+
+impl CatalogChangeset {
+    /// Reports whether the account already matches the catalog.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Reports whether the changeset contains any conflicts.
+    pub fn has_conflicts(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| matches!(change, CatalogChange::Conflict { .. }))
+    }
+}
+
+impl fmt::Display for CatalogChangeset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "no changes");
+        }
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes the metadata changes required to turn `existing` into `desired`.
+fn diff_metadata(
+    existing: &BTreeMap<String, String>,
+    desired: &BTreeMap<String, String>,
+) -> BTreeMap<String, Option<String>> {
+    let mut metadata = BTreeMap::new();
+    for (key, value) in desired {
+        if existing.get(key) != Some(value) {
+            metadata.insert(key.clone(), Some(value.clone()));
+        }
+    }
+    for key in existing.keys() {
+        if !desired.contains_key(key) {
+            metadata.insert(key.clone(), None);
+        }
+    }
+    metadata
+}
+
+/// Looks up the ID of the named catalog object.
+fn resolve_id(ids: &BTreeMap<String, String>, kind: &str, name: &str) -> Result<String, Error> {
+    ids.get(name).cloned().ok_or_else(|| Error::InvalidRequest {
+        detail: format!("catalog references unknown {kind} {name:?}"),
+    })
+}
+
+impl Catalog {
+    /// Computes the changes required to bring an Orb account with the
+    /// specified items, billable metrics, prices and plans in line with the
+    /// catalog.
+    ///
+    /// See [`Client::diff_catalog`], which fetches the account's existing
+    /// objects and calls this method.
+    pub fn diff(
+        &self,
+        items: &[Item],
+        metrics: &[BillableMetric],
+        prices: &[Price],
+        plans: &[Plan],
+    ) -> CatalogChangeset {
+        let item_ids: BTreeMap<String, String> = items
+            .iter()
+            .map(|item| (item.name.clone(), item.id.clone()))
+            .collect();
+        let metric_ids = metrics
+            .iter()
+            .map(|metric| (metric.name.clone(), metric.id.clone()))
+            .collect();
+        let metric_names = metrics
+            .iter()
+            .map(|metric| (&*metric.id, &*metric.name))
+            .collect();
+        let metrics: BTreeMap<&str, &BillableMetric> = metrics
+            .iter()
+            .map(|metric| (&*metric.name, metric))
+            .collect();
+        let prices: BTreeMap<&str, &Price> = prices
+            .iter()
+            .filter_map(|price| price.external_id().map(|id| (id, price)))
+            .collect();
+        // Plans that are no longer active may retain their external ID, in
+        // which case the external ID cannot be reused. Prefer active plans
+        // if several plans share an external ID.
+        let mut by_external_id: BTreeMap<&str, &Plan> = BTreeMap::new();
+        for plan in plans {
+            let Some(external_id) = &plan.external_id else {
+                continue;
+            };
+            let entry = by_external_id.entry(external_id).or_insert(plan);
+            if plan.status == Some(PlanStatus::Active) {
+                *entry = plan;
+            }
+        }
+        let plans = by_external_id;
+
+        let mut changes = vec![];
+
+        for item in &self.items {
+            if !item_ids.contains_key(&item.name) {
+                changes.push(CatalogChange::CreateItem(item.clone()));
+            }
+        }
+
+        for metric in &self.metrics {
+            let Some(existing) = metrics.get(&*metric.name) else {
+                changes.push(CatalogChange::CreateMetric(metric.clone()));
+                continue;
+            };
+            let mut differences = vec![];
+            if existing.item.name != metric.item {
+                differences.push("item");
+            }
+            if metric.description.is_some() && existing.description != metric.description {
+                differences.push("description");
+            }
+            if !differences.is_empty() {
+                changes.push(CatalogChange::Conflict {
+                    kind: "metric",
+                    external_id: metric.name.clone(),
+                    detail: format!(
+                        "existing metric differs from catalog in {}",
+                        differences.join(", ")
+                    ),
+                });
+            }
+            let metadata = diff_metadata(&existing.metadata, &metric.metadata);
+            if !metadata.is_empty() {
+                changes.push(CatalogChange::UpdateMetricMetadata {
+                    id: existing.id.clone(),
+                    name: metric.name.clone(),
+                    metadata,
+                });
+            }
+        }
+
+        for price in &self.prices {
+            match prices.get(&*price.external_id) {
+                None => changes.push(CatalogChange::CreatePrice(price.clone())),
+                Some(existing) => {
+                    let matches = CatalogPriceSpec::from_price(existing, &metric_names)
+                        .map(|spec| spec.canonicalize() == price.spec.canonicalize())
+                        .unwrap_or(false)
+                        && existing.base().and_then(|b| b.currency.as_deref())
+                            == Some(price.currency.alpha_code());
+                    if !matches {
+                        changes.push(CatalogChange::Conflict {
+                            kind: "price",
+                            external_id: price.external_id.clone(),
+                            detail: "existing price differs from catalog".into(),
+                        });
+                    }
+                }
+            }
+        }
+
+        for plan in &self.plans {
+            let Some(existing) = plans.get(&*plan.external_id) else {
+                changes.push(CatalogChange::CreatePlan(plan.clone()));
+                continue;
+            };
+            if existing.status != Some(PlanStatus::Active) {
+                let status = match &existing.status {
+                    Some(status) => status.to_string(),
+                    None => "unknown".into(),
+                };
+                changes.push(CatalogChange::Conflict {
+                    kind: "plan",
+                    external_id: plan.external_id.clone(),
+                    detail: format!("existing plan has status {status}"),
+                });
+                continue;
+            }
+            let mut differences = vec![];
+            if existing.name.as_deref() != Some(&*plan.name) {
+                differences.push("name");
+            }
+            if existing.currency.as_deref() != Some(plan.currency.alpha_code()) {
+                differences.push("currency");
+            }
+            if plan.net_terms.is_some() && existing.net_terms != plan.net_terms {
+                differences.push("net terms");
+            }
+            if plan.default_invoice_memo.is_some()
+                && existing.default_invoice_memo != plan.default_invoice_memo
+            {
+                differences.push("default invoice memo");
+            }
+            let existing_prices: Option<Vec<String>> = existing
+                .prices
+                .iter()
+                .map(|p| {
+                    CatalogPriceSpec::from_price(p, &metric_names)
+                        .map(|s| s.canonicalize().to_string())
+                })
+                .collect();
+            let existing_prices = existing_prices.map(|mut prices| {
+                prices.sort();
+                prices
+            });
+            let mut desired_prices: Vec<String> = plan
+                .prices
+                .iter()
+                .map(|p| p.canonicalize().to_string())
+                .collect();
+            desired_prices.sort();
+            if existing_prices.as_ref() != Some(&desired_prices) {
+                differences.push("prices");
+            }
+            if !differences.is_empty() {
+                changes.push(CatalogChange::Conflict {
+                    kind: "plan",
+                    external_id: plan.external_id.clone(),
+                    detail: format!(
+                        "existing plan differs from catalog in {}",
+                        differences.join(", ")
+                    ),
+                });
+            }
+            let metadata = diff_metadata(&existing.metadata, &plan.metadata);
+            if !metadata.is_empty() {
+                changes.push(CatalogChange::UpdatePlanMetadata {
+                    id: existing.id.clone(),
+                    external_id: plan.external_id.clone(),
+                    metadata,
+                });
+            }
+        }
+
+        if self.prune {
+            let desired: BTreeSet<&str> = self.plans.iter().map(|p| &*p.external_id).collect();
+            for (external_id, plan) in &plans {
+                if plan.status == Some(PlanStatus::Active) && !desired.contains(external_id) {
+                    changes.push(CatalogChange::ArchivePlan {
+                        id: plan.id.clone(),
+                        external_id: external_id.to_string(),
+                    });
+                }
+            }
+        }
+
+        CatalogChangeset {
+            changes,
+            item_ids,
+            metric_ids,
+        }
+    }
+}
+
+impl Client {
+    /// Computes the changes required to bring the Orb account in line with
+    /// `catalog`.
+    ///
+    /// Items and billable metrics are matched by name, and prices and plans
+    /// by external ID. The account is not modified.
+    ///
+    /// Objects missing from the account are created, and the metadata of
+    /// billable metrics and plans is updated in place. Orb does not permit
+    /// any other changes to existing billable metrics, prices and plans, so
+    /// any other difference is reported as a [`CatalogChange::Conflict`], as
+    /// is a catalog plan whose external ID belongs to a plan that is no
+    /// longer active. The SQL query of existing billable metrics is not
+    /// compared, as Orb does not report it.
+    pub async fn diff_catalog(&self, catalog: &Catalog) -> Result<CatalogChangeset, Error> {
+        let items: Vec<Item> = self
+            .list_items(&ListParams::DEFAULT.page_size(500))
+            .try_collect()
+            .await?;
+        let metrics: Vec<BillableMetric> = self
+            .list_billable_metrics(&ListParams::DEFAULT.page_size(500))
+            .try_collect()
+            .await?;
+        let prices: Vec<Price> = self
+            .list_prices(&ListParams::DEFAULT.page_size(500))
+            .try_collect()
+            .await?;
+        let plans: Vec<Plan> = self
+            .list_plans(&PlanListParams::DEFAULT.page_size(500))
+            .try_collect()
+            .await?;
+        Ok(catalog.diff(&items, &metrics, &prices, &plans))
+    }
+
+    /// Applies the changes in `changeset` to the Orb account.
+    ///
+    /// Returns [`Error::InvalidRequest`] without modifying the account if the
+    /// changeset contains conflicts. Changes are applied in order; if a
+    /// change fails, the changes after it are not applied.
+    ///
+    /// Returns the changes that must be applied manually, i.e., any
+    /// [`CatalogChange::ArchivePlan`] changes.
+    pub async fn apply_catalog_changeset<'a>(
+        &self,
+        changeset: &'a CatalogChangeset,
+    ) -> Result<Vec<&'a CatalogChange>, Error> {
+        if changeset.has_conflicts() {
+            return Err(Error::InvalidRequest {
+                detail: format!("catalog changeset has conflicts:\n{changeset}"),
+            });
+        }
+        let mut item_ids = changeset.item_ids.clone();
+        let mut metric_ids = changeset.metric_ids.clone();
+        let mut manual = vec![];
+        for change in &changeset.changes {
+            match change {
+                CatalogChange::CreateItem(item) => {
+                    let created = self
+                        .create_item(&CreateItemRequest { name: &item.name })
+                        .await?;
+                    item_ids.insert(created.name, created.id);
+                }
+                CatalogChange::CreateMetric(metric) => {
+                    let item_id = resolve_id(&item_ids, "item", &metric.item)?;
+                    let created = self
+                        .create_billable_metric(&CreateBillableMetricRequest {
+                            name: &metric.name,
+                            description: metric.description.as_deref(),
+                            item_id: &item_id,
+                            sql: &metric.sql,
+                            metadata: Some(&metric.metadata),
+                        })
+                        .await?;
+                    metric_ids.insert(created.name, created.id);
+                }
+                CatalogChange::CreatePrice(price) => {
+                    let item_id = resolve_id(&item_ids, "item", &price.spec.item)?;
+                    let metric_id = price
+                        .spec
+                        .billable_metric
+                        .as_ref()
+                        .map(|name| resolve_id(&metric_ids, "metric", name))
+                        .transpose()?;
+                    self.create_price(&CreatePriceRequest {
+                        name: &price.spec.name,
+                        item_id: &item_id,
+                        cadence: price.spec.cadence.clone(),
+                        currency: price.currency,
+                        model: price.spec.model.clone(),
+                        external_id: Some(&price.external_id),
+                        billable_metric_id: metric_id.as_deref(),
+                        fixed_price_quantity: price.spec.fixed_price_quantity,
+                        invoice_grouping_key: None,
+                        metadata: None,
+                    })
+                    .await?;
+                }
+                CatalogChange::CreatePlan(plan) => {
+                    let price_ids = plan
+                        .prices
+                        .iter()
+                        .map(|price| {
+                            let item_id = resolve_id(&item_ids, "item", &price.item)?;
+                            let metric_id = price
+                                .billable_metric
+                                .as_ref()
+                                .map(|name| resolve_id(&metric_ids, "metric", name))
+                                .transpose()?;
+                            Ok((item_id, metric_id))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    self.create_plan(&CreatePlanRequest {
+                        name: &plan.name,
+                        currency: plan.currency,
+                        prices: plan
+                            .prices
+                            .iter()
+                            .zip(&price_ids)
+                            .map(|(price, (item_id, metric_id))| CreatePlanPriceRequest {
+                                name: &price.name,
+                                item_id,
+                                cadence: price.cadence.clone(),
+                                model: price.model.clone(),
+                                external_id: None,
+                                billable_metric_id: metric_id.as_deref(),
+                                fixed_price_quantity: price.fixed_price_quantity,
+                                invoice_grouping_key: None,
+                            })
+                            .collect(),
+                        external_id: Some(&plan.external_id),
+                        metadata: Some(&plan.metadata),
+                        net_terms: plan.net_terms,
+                        default_invoice_memo: plan.default_invoice_memo.as_deref(),
+                    })
+                    .await?;
+                }
+                CatalogChange::UpdateMetricMetadata { id, metadata, .. } => {
+                    self.update_billable_metric(
+                        id,
+                        &UpdateBillableMetricRequest {
+                            metadata: Some(metadata),
+                        },
+                    )
+                    .await?;
+                }
+                CatalogChange::UpdatePlanMetadata { id, metadata, .. } => {
+                    self.update_plan(
+                        id,
+                        &UpdatePlanRequest {
+                            metadata: Some(metadata),
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+                }
+                CatalogChange::ArchivePlan { .. } => manual.push(change),
+                CatalogChange::Conflict { .. } => unreachable!("conflicts rejected above"),
+            }
+        }
+        Ok(manual)
+    }
+}
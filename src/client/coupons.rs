@@ -0,0 +1,203 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_core::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::client::discounts::Discount;
+use crate::client::subscriptions::Subscription;
+use crate::client::Client;
+use crate::config::ListParams;
+use crate::error::Error;
+use crate::money::Amount;
+use crate::util::StrIteratorExt;
+
+const COUPONS_PATH: [&str; 1] = ["coupons"];
+
+/// An Orb coupon.
+///
+/// A coupon grants a discount to the subscriptions that redeem it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Coupon {
+    /// The Orb-assigned unique identifier for the coupon.
+    pub id: String,
+    /// The code that customers use to redeem the coupon.
+    pub redemption_code: String,
+    /// The discount that the coupon grants.
+    pub discount: Discount,
+    /// The number of times the coupon has been redeemed.
+    pub times_redeemed: i64,
+    /// The number of months for which the discount applies after the coupon
+    /// is redeemed.
+    ///
+    /// If `None`, the discount applies indefinitely.
+    pub duration_in_months: Option<i64>,
+    /// The maximum number of times the coupon can be redeemed.
+    ///
+    /// If `None`, the coupon can be redeemed any number of times.
+    pub max_redemptions: Option<i64>,
+    /// The time at which the coupon was archived, if it has been archived.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub archived_at: Option<OffsetDateTime>,
+}
+
+/// The subset of [`Coupon`] used in create requests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CreateCouponRequest<'a> {
+    /// The code that customers use to redeem the coupon.
+    pub redemption_code: &'a str,
+    /// The discount that the coupon grants.
+    pub discount: CouponDiscountRequest,
+    /// The number of months for which the discount applies after the coupon
+    /// is redeemed.
+    ///
+    /// If `None`, the discount applies indefinitely.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_in_months: Option<i64>,
+    /// The maximum number of times the coupon can be redeemed.
+    ///
+    /// If `None`, the coupon can be redeemed any number of times.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_redemptions: Option<i64>,
+}
+
+/// The discount granted by a coupon in a [`CreateCouponRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "discount_type", rename_all = "snake_case")]
+pub enum CouponDiscountRequest {
+    /// A discount of a percentage of the amount.
+    Percentage {
+        /// The fraction of the amount to discount, between 0 and 1.
+        #[serde(with = "crate::money::number")]
+        percentage_discount: Amount,
+    },
+    /// A discount of a fixed amount.
+    Amount {
+        /// The amount to discount.
+        amount_discount: Amount,
+    },
+}
+
+/// Parameters for a coupon list operation.
+#[derive(Debug, Clone)]
+pub struct CouponListParams<'a> {
+    inner: ListParams,
+    redemption_code: Option<&'a str>,
+    show_archived: bool,
+}
+
+impl<'a> Default for CouponListParams<'a> {
+    fn default() -> CouponListParams<'a> {
+        CouponListParams::DEFAULT
+    }
+}
+
+impl<'a> CouponListParams<'a> {
+    /// The default coupon list parameters.
+    ///
+    /// Exposed as a constant for use in constant evaluation contexts.
+    pub const DEFAULT: CouponListParams<'static> = CouponListParams {
+        inner: ListParams::DEFAULT,
+        redemption_code: None,
+        show_archived: false,
+    };
+
+    /// Sets the page size for the list operation.
+    ///
+    /// See [`ListParams::page_size`].
+    pub const fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Filters the listing to the coupon with the specified redemption code.
+    pub const fn redemption_code(mut self, redemption_code: &'a str) -> Self {
+        self.redemption_code = Some(redemption_code);
+        self
+    }
+
+    /// Whether to include archived coupons in the listing.
+    ///
+    /// Archived coupons are excluded by default.
+    pub const fn show_archived(mut self, show_archived: bool) -> Self {
+        self.show_archived = show_archived;
+        self
+    }
+}
+
+impl Client {
+    /// Lists coupons as configured by `params`.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn list_coupons(
+        &self,
+        params: &CouponListParams,
+    ) -> impl Stream<Item = Result<Coupon, Error>> + '_ {
+        let req = self.build_request(Method::GET, COUPONS_PATH);
+        let req = match params.redemption_code {
+            None => req,
+            Some(code) => req.query(&[("redemption_code", code)]),
+        };
+        let req = req.query(&[("show_archived", params.show_archived)]);
+        self.stream_paginated_request(&params.inner, req)
+    }
+
+    /// Creates a new coupon.
+    pub async fn create_coupon(&self, coupon: &CreateCouponRequest<'_>) -> Result<Coupon, Error> {
+        let req = self.build_request(Method::POST, COUPONS_PATH);
+        let req = req.json(coupon);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Gets a coupon by ID.
+    pub async fn get_coupon(&self, id: &str) -> Result<Coupon, Error> {
+        let req = self.build_request(Method::GET, COUPONS_PATH.chain_one(id));
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Archives a coupon by ID.
+    ///
+    /// Archived coupons can no longer be redeemed. Subscriptions that have
+    /// already redeemed the coupon are unaffected.
+    pub async fn archive_coupon(&self, id: &str) -> Result<Coupon, Error> {
+        let req = self.build_request(
+            Method::POST,
+            COUPONS_PATH.chain_one(id).chain_one("archive"),
+        );
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Lists the subscriptions that have redeemed a coupon.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn list_coupon_subscriptions(
+        &self,
+        id: &str,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Subscription, Error>> + '_ {
+        let req = self.build_request(
+            Method::GET,
+            COUPONS_PATH.chain_one(id).chain_one("subscriptions"),
+        );
+        self.stream_subscriptions(params, req)
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_core::Stream;
+use futures_util::stream::TryStreamExt;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use time::OffsetDateTime;
+
+use crate::client::customers::CustomerId;
+use crate::client::invoices::InvoiceCustomer;
+use crate::client::Client;
+use crate::config::ListParams;
+use crate::error::Error;
+use crate::money::Amount;
+use crate::util::StrIteratorExt;
+
+const CREDIT_NOTES_PATH: [&str; 1] = ["credit_notes"];
+
+/// An Orb credit note.
+///
+/// A credit note records a refund or adjustment against an issued invoice.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CreditNote {
+    /// The Orb-assigned unique identifier for the credit note.
+    pub id: String,
+    /// The unique identifier for the credit note shown to customers.
+    pub credit_note_number: String,
+    /// The ID of the invoice that the credit note applies to.
+    pub invoice_id: String,
+    /// The customer to whom the credit note was issued.
+    pub customer: InvoiceCustomer,
+    /// The type of the credit note.
+    #[serde(rename = "type")]
+    pub type_: CreditNoteType,
+    /// The reason for the credit note.
+    pub reason: Option<CreditNoteReason>,
+    /// An optional memo supplied on the credit note.
+    pub memo: Option<String>,
+    /// The total before any discounts and minimums are applied.
+    pub subtotal: Amount,
+    /// The total after any discounts and minimums are applied.
+    pub total: Amount,
+    /// The line items of the credit note.
+    pub line_items: Vec<CreditNoteLineItem>,
+    /// The link to download the PDF representation of the credit note.
+    #[serde(rename = "credit_note_pdf")]
+    pub pdf_url: Option<String>,
+    /// The time at which the credit note was created.
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// The time at which the credit note was voided, if it has been voided.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub voided_at: Option<OffsetDateTime>,
+}
+
+/// A line item in a [`CreditNote`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CreditNoteLineItem {
+    /// The Orb-assigned unique identifier for the line item.
+    pub id: String,
+    /// The name of the line item.
+    pub name: String,
+    /// The amount of the line item, after discounts.
+    pub amount: Amount,
+    /// The amount of the line item, before discounts.
+    pub subtotal: Amount,
+    /// The quantity of the line item, if applicable.
+    #[serde(default, with = "crate::money::number::option")]
+    pub quantity: Option<Amount>,
+}
+
+/// The type of a [`CreditNote`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum CreditNoteType {
+    /// The credit note refunds a payment.
+    Refund,
+    /// The credit note adjusts the amount due on an invoice.
+    Adjustment,
+    /// An unknown credit note type.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The reason for a [`CreditNote`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum CreditNoteReason {
+    /// The invoice was a duplicate.
+    Duplicate,
+    /// The invoice was fraudulent.
+    Fraudulent,
+    /// The customer's order changed.
+    OrderChange,
+    /// The customer was unsatisfied with the product.
+    ProductUnsatisfactory,
+    /// An unknown reason.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The subset of [`CreditNote`] used in create requests.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CreateCreditNoteRequest<'a> {
+    /// The invoice line items to credit.
+    ///
+    /// All line items must belong to the same invoice.
+    pub line_items: &'a [CreateCreditNoteLineItemRequest<'a>],
+    /// The reason for the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<CreditNoteReason>,
+    /// An optional memo to attach to the credit note.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+}
+
+/// A line item in a [`CreateCreditNoteRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CreateCreditNoteLineItemRequest<'a> {
+    /// The ID of the invoice line item to credit.
+    pub invoice_line_item_id: &'a str,
+    /// The amount to credit.
+    pub amount: Amount,
+}
+
+/// Parameters for a credit note list operation.
+#[derive(Debug, Clone)]
+pub struct CreditNoteListParams<'a> {
+    inner: ListParams,
+    customer_filter: Option<CustomerId<'a>>,
+}
+
+impl<'a> Default for CreditNoteListParams<'a> {
+    fn default() -> CreditNoteListParams<'a> {
+        CreditNoteListParams::DEFAULT
+    }
+}
+
+impl<'a> CreditNoteListParams<'a> {
+    /// The default credit note list parameters.
+    ///
+    /// Exposed as a constant for use in constant evaluation contexts.
+    pub const DEFAULT: CreditNoteListParams<'static> = CreditNoteListParams {
+        inner: ListParams::DEFAULT,
+        customer_filter: None,
+    };
+
+    /// Sets the page size for the list operation.
+    ///
+    /// See [`ListParams::page_size`].
+    pub const fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Filters the listing to the specified customer ID.
+    ///
+    /// Orb does not support this filter, so it is applied client side. The
+    /// listing still fetches every credit note in the account.
+    pub const fn customer_id(mut self, filter: CustomerId<'a>) -> Self {
+        self.customer_filter = Some(filter);
+        self
+    }
+}
+
+impl Client {
+    /// Lists credit notes as configured by `params`.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn list_credit_notes<'a>(
+        &'a self,
+        params: &CreditNoteListParams<'a>,
+    ) -> impl Stream<Item = Result<CreditNote, Error>> + 'a {
+        let req = self.build_request(Method::GET, CREDIT_NOTES_PATH);
+        let customer_filter = params.customer_filter.clone();
+        self.stream_paginated_request(&params.inner, req)
+            .try_filter(move |credit_note: &CreditNote| {
+                let customer = &credit_note.customer;
+                let matches = match &customer_filter {
+                    None => true,
+                    Some(CustomerId::Orb(id)) => customer.id == *id,
+                    Some(CustomerId::External(id)) => customer.external_id.as_deref() == Some(id),
+                };
+                async move { matches }
+            })
+    }
+
+    /// Creates a new credit note.
+    pub async fn create_credit_note(
+        &self,
+        credit_note: &CreateCreditNoteRequest<'_>,
+    ) -> Result<CreditNote, Error> {
+        let req = self.build_request(Method::POST, CREDIT_NOTES_PATH);
+        let req = req.json(credit_note);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Gets a credit note by ID.
+    pub async fn get_credit_note(&self, id: &str) -> Result<CreditNote, Error> {
+        let req = self.build_request(Method::GET, CREDIT_NOTES_PATH.chain_one(id));
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+}
@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use codes_iso_3166::part_1::CountryCode;
 use codes_iso_4217::CurrencyCode;
 use futures_core::Stream;
@@ -23,20 +25,17 @@ use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use time::format_description::well_known::Rfc3339;
 use time::{OffsetDateTime, UtcOffset};
 
+use crate::client::prices::Price;
 use crate::client::taxes::{TaxId, TaxIdRequest};
 use crate::client::Client;
-use crate::config::ListParams;
+use crate::config::{CreatedAtFilter, ListParams};
 use crate::error::Error;
-use crate::serde::Empty;
+use crate::money::{Amount, Money};
+use crate::serde::{ArrayResponse, Empty};
 use crate::util::StrIteratorExt;
 
 const CUSTOMERS_PATH: [&str; 1] = ["customers"];
 
-#[derive(Deserialize)]
-struct ArrayResponse<T> {
-    data: Vec<T>,
-}
-
 /// A customer ID.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum CustomerId<'a> {
@@ -89,6 +88,22 @@ pub struct CreateCustomerRequest<'a> {
     /// The tax ID details to display on the customer's invoice.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_id: Option<TaxIdRequest<'a>>,
+    /// Determines whether issued invoices for this customer will
+    /// automatically be charged with the saved payment method on the due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_collection: Option<bool>,
+    /// Whether Orb emails invoices to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_delivery: Option<bool>,
+    /// Configures how the customer is included in reports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reporting_configuration: Option<CustomerReportingConfiguration>,
+    /// Configures automated tax calculation for the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_configuration: Option<CustomerTaxConfigurationRequest<'a>>,
+    /// Arbitrary metadata to attach to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, String>>,
     /// An idempotency key can ensure that if the same request comes in
     /// multiple times in a 48-hour period, only one makes changes.
     // NOTE: this is passed in a request header, not the body
@@ -121,6 +136,51 @@ pub struct UpdateCustomerRequest<'a> {
     /// The tax ID details to display on the customer's invoice.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_id: Option<TaxIdRequest<'a>>,
+    /// Determines whether issued invoices for this customer will
+    /// automatically be charged with the saved payment method on the due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_collection: Option<bool>,
+    /// Whether Orb emails invoices to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_delivery: Option<bool>,
+    /// Configures how the customer is included in reports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reporting_configuration: Option<CustomerReportingConfiguration>,
+    /// Configures automated tax calculation for the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_configuration: Option<CustomerTaxConfigurationRequest<'a>>,
+    /// Arbitrary metadata to attach to the customer.
+    ///
+    /// Keys present in the map replace the customer's existing metadata.
+    /// Keys mapped to `None` are removed from the customer's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, Option<String>>>,
+}
+
+/// Configures automated tax calculation for a customer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "tax_provider", rename_all = "snake_case")]
+pub enum CustomerTaxConfigurationRequest<'a> {
+    /// Taxes are calculated by Avalara.
+    Avalara {
+        /// Whether the customer is exempt from tax.
+        tax_exempt: bool,
+        /// The Avalara exemption code that applies to the customer.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tax_exemption_code: Option<&'a str>,
+    },
+    /// Taxes are calculated by TaxJar.
+    Taxjar {
+        /// Whether the customer is exempt from tax.
+        tax_exempt: bool,
+    },
+}
+
+/// Configures how a customer is included in reports.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct CustomerReportingConfiguration {
+    /// Whether the customer is exempt from automated tax reporting.
+    pub exempt: bool,
 }
 
 /// Configures an external payment or invoicing solution for a customer.
@@ -176,15 +236,34 @@ pub struct Customer {
     pub currency: Option<CurrencyCode>,
     /// The tax ID details to display on the customer's invoice.
     pub tax_id: Option<TaxId>,
-    /// Undocumented upstream.
+    /// Determines whether issued invoices for this customer will
+    /// automatically be charged with the saved payment method on the due date.
     pub auto_collection: bool,
+    /// Whether Orb emails invoices to the customer.
+    #[serde(default)]
+    pub email_delivery: bool,
+    /// Configures how the customer is included in reports.
+    #[serde(default)]
+    pub reporting_configuration: Option<CustomerReportingConfiguration>,
     /// The customer's current balance in their currency.
-    pub balance: String,
+    pub balance: Amount,
     /// The time at which the customer was created.
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     /// An authenticated URL link to the customer's private Orb dashboard portal.
     pub portal_url: Option<String>,
+    /// Arbitrary metadata that is attached to the customer.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl Customer {
+    /// Returns the customer's balance in the customer's currency, if the
+    /// customer's currency is known.
+    pub fn balance_money(&self) -> Option<Money> {
+        self.currency
+            .map(|currency| Money::new(self.balance, currency))
+    }
 }
 
 /// A payment provider.
@@ -286,7 +365,8 @@ pub struct CreditLedgerInvoiceSettingsRequestParams<'a> {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct AddIncrementCreditLedgerEntryRequestParams<'a> {
     /// The amount to credit the customer for.
-    pub amount: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub amount: Amount,
     /// An optional description for the credit operation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
@@ -300,7 +380,7 @@ pub struct AddIncrementCreditLedgerEntryRequestParams<'a> {
     pub effective_date: Option<OffsetDateTime>,
     /// The price per credit.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub per_unit_cost_basis: Option<&'a str>,
+    pub per_unit_cost_basis: Option<Amount>,
     /// Invoicing settings for the credit increment request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice_settings: Option<CreditLedgerInvoiceSettingsRequestParams<'a>>,
@@ -318,7 +398,8 @@ pub enum VoidReason {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct AddVoidCreditLedgerEntryRequestParams<'a> {
     /// The number of credits to void.
-    pub amount: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub amount: Amount,
     /// The ID of the credit ledger block to void.
     pub block_id: &'a str,
     /// An optional reason for the void.
@@ -335,12 +416,13 @@ pub struct CustomerCreditBlock {
     /// The Orb-assigned unique identifier for the credit block.
     pub id: String,
     /// The remaining credit balance for the block.
-    pub balance: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub balance: Amount,
     /// The date on which the block's balance will expire.
     #[serde(with = "time::serde::rfc3339::option")]
     pub expiry_date: Option<OffsetDateTime>,
     /// The price per credit.
-    pub per_unit_cost_basis: Option<String>,
+    pub per_unit_cost_basis: Option<Amount>,
 }
 
 /// The type of ledger entry
@@ -389,7 +471,7 @@ pub struct LedgerEntryCreditBlock {
     #[serde(with = "time::serde::rfc3339::option")]
     pub expiry_date: Option<OffsetDateTime>,
     /// The price per credit.
-    pub per_unit_cost_basis: Option<String>,
+    pub per_unit_cost_basis: Option<Amount>,
 }
 
 /// Core ledger entry fields.
@@ -404,11 +486,14 @@ pub struct BaseLedgerEntry {
     /// The customer identifiers associated with the ledger entry.
     pub customer: CustomerIdentifier,
     /// The customer's credit balance before application of the ledger operation.
-    pub starting_balance: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub starting_balance: Amount,
     /// The customer's credit balance after application of the ledger operation.
-    pub ending_balance: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub ending_balance: Amount,
     /// The amount granted to the ledger.
-    pub amount: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub amount: Amount,
     /// The date the ledger entry was created.
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
@@ -435,7 +520,8 @@ pub struct VoidLedgerEntry {
     /// The reason the ledger entry was voided.
     pub void_reason: Option<String>,
     /// The amount voided from the ledger.
-    pub void_amount: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub void_amount: Amount,
 }
 
 /// A record of a ledger void initialization operation.
@@ -450,7 +536,105 @@ pub struct VoidInitiatedLedgerEntry {
     /// The reason the ledger entry was voided.
     pub void_reason: Option<String>,
     /// The amount voided from the ledger.
-    pub void_amount: serde_json::Number,
+    #[serde(with = "crate::money::number")]
+    pub void_amount: Amount,
+}
+
+/// A transaction that changed a customer's balance.
+///
+/// The customer's balance is applied to the amount due on their invoices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BalanceTransaction {
+    /// The Orb-assigned unique identifier for the transaction.
+    pub id: String,
+    /// Whether the transaction increased or decreased the balance.
+    #[serde(rename = "type")]
+    pub type_: BalanceTransactionType,
+    /// The action that caused the transaction.
+    pub action: BalanceTransactionAction,
+    /// The value of the transaction.
+    pub amount: Amount,
+    /// The customer's balance before the transaction.
+    pub starting_balance: Amount,
+    /// The customer's balance after the transaction.
+    pub ending_balance: Amount,
+    /// An optional description of the transaction.
+    pub description: Option<String>,
+    /// The invoice associated with the transaction, if any.
+    pub invoice: Option<BalanceTransactionInvoice>,
+    /// The credit note associated with the transaction, if any.
+    pub credit_note: Option<BalanceTransactionCreditNote>,
+    /// The time at which the transaction was created.
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Whether a [`BalanceTransaction`] increased or decreased the balance.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceTransactionType {
+    /// The transaction increased the balance.
+    Increment,
+    /// The transaction decreased the balance.
+    Decrement,
+    /// An unknown transaction type.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The action that caused a [`BalanceTransaction`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceTransactionAction {
+    /// The balance was applied to an invoice.
+    AppliedToInvoice,
+    /// The balance was adjusted manually.
+    ManualAdjustment,
+    /// A prorated refund was issued.
+    ProratedRefund,
+    /// A prorated refund was reverted.
+    RevertProratedRefund,
+    /// The balance applied to an invoice was returned when the invoice was
+    /// voided.
+    ReturnFromVoiding,
+    /// A credit note was applied to the balance.
+    CreditNoteApplied,
+    /// A credit note applied to the balance was voided.
+    CreditNoteVoided,
+    /// An overpayment was refunded.
+    OverpaymentRefund,
+    /// An unknown action.
+    #[serde(other)]
+    Other(String),
+}
+
+/// Identifies the invoice associated with a [`BalanceTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BalanceTransactionInvoice {
+    /// The Orb-assigned unique identifier for the invoice.
+    pub id: String,
+}
+
+/// Identifies the credit note associated with a [`BalanceTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BalanceTransactionCreditNote {
+    /// The Orb-assigned unique identifier for the credit note.
+    pub id: String,
+}
+
+/// The subset of [`BalanceTransaction`] used in create requests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CreateBalanceTransactionRequest<'a> {
+    /// Whether to increase or decrease the balance.
+    #[serde(rename = "type")]
+    pub type_: BalanceTransactionType,
+    /// The value of the transaction.
+    pub amount: Amount,
+    /// An optional description of the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
 }
 
 /// The view mode for a cost breakdown.
@@ -465,14 +649,14 @@ pub enum CostViewMode {
 }
 
 #[derive(Debug, Default, Clone)]
-struct CustomerCostParamsFilter<'a> {
+pub(crate) struct CustomerCostParamsFilter<'a> {
     timeframe_start: Option<&'a OffsetDateTime>,
     timeframe_end: Option<&'a OffsetDateTime>,
     view_mode: Option<CostViewMode>,
     group_by: Option<&'a str>,
 }
 
-trait Filterable<T> {
+pub(crate) trait Filterable<T> {
     /// Apply the filter to a request.
     fn apply(self, filter: &T) -> Self;
 }
@@ -513,7 +697,7 @@ impl Filterable<CustomerCostParamsFilter<'_>> for RequestBuilder {
 /// Parameters for a Customer Costs query.
 #[derive(Debug, Default, Clone)]
 pub struct CustomerCostParams<'a> {
-    filter: CustomerCostParamsFilter<'a>,
+    pub(crate) filter: CustomerCostParamsFilter<'a>,
 }
 
 impl<'a> CustomerCostParams<'a> {
@@ -544,12 +728,12 @@ impl<'a> CustomerCostParams<'a> {
 }
 
 /// A group of costs for a given timeframe.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CustomerCostBucket {
     /// Total costs for the timeframe, excluding any minimums and discounts.
-    pub subtotal: String,
+    pub subtotal: Amount,
     /// Total costs for the timeframe, including any minimums and discounts.
-    pub total: String,
+    pub total: Amount,
     /// The starting point for the timeframe.
     #[serde(with = "time::serde::rfc3339")]
     pub timeframe_start: OffsetDateTime,
@@ -561,16 +745,17 @@ pub struct CustomerCostBucket {
 }
 
 /// The cost for a given Price within a timeframe.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CustomerCostPriceBlock {
     /// The price's quantity for the timeframe.
-    pub quantity: Option<serde_json::Number>,
+    #[serde(default, with = "crate::money::number::option")]
+    pub quantity: Option<Amount>,
     /// The price's contributions for the timeframe, excluding any minimums and discounts.
-    pub subtotal: String,
+    pub subtotal: Amount,
     /// The price's contributions for the timeframe, including any minimums and discounts.
-    pub total: String,
+    pub total: Amount,
     /// The price that can be billed on a subscription.
-    pub price: CustomerCostPriceBlockPrice,
+    pub price: Price,
     /// The price costs per grouping key.
     pub price_groups: Option<Vec<CustomerCostPriceBlockPriceGroup>>,
 }
@@ -589,34 +774,7 @@ pub struct CustomerCostPriceBlockPriceGroup {
     /// Total costs for this group for the timeframe, excluding any minimums and discounts.
     // this should be thought of as a "subtotal" to align with the rest of the API, but we're
     // keeping the existing Orb terminology.
-    pub total: String,
-}
-
-/// The type of pricing
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(tag = "model_type")]
-pub enum CustomerCostPriceBlockPrice {
-    /// Sets of unit prices in a one or two-dimensional matrix.
-    #[serde(rename = "matrix")]
-    Matrix(CustomerCostPriceBlockMatrixPrice),
-    /// A fixed amount per unit of usage.
-    #[serde(rename = "unit")]
-    Unit(CustomerCostPriceBlockUnitPrice),
-    // TODO: Add support for additional pricing models
-}
-
-/// Matrix pricing details.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CustomerCostPriceBlockMatrixPrice {
-    /// The Orb-assigned unique identifier for the price block.
-    pub id: String,
-    /// An optional user-defined ID for this price resource.
-    #[serde(rename = "external_price_id")]
-    pub external_id: Option<String>,
-    /// Information about the item being priced.
-    pub item: CustomerCostItem,
-    /// The configuration for this matrix price.
-    pub matrix_config: CustomerCostPriceBlockMatrixPriceConfig,
+    pub total: Amount,
 }
 
 /// An item being priced.
@@ -628,61 +786,95 @@ pub struct CustomerCostItem {
     pub name: String,
 }
 
-/// Configuration for a pricing matrix.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CustomerCostPriceBlockMatrixPriceConfig {
-    /// The fallback unit amount.
-    pub default_unit_amount: String,
-    /// A collection of dimensions modeled by the matrix.
-    pub dimensions: Vec<Option<String>>,
-    /// All pricing values configured for the matrix.
-    pub matrix_values: Vec<CustomerCostPriceBlockMatrixPriceValue>,
+/// Parameters for a customer list operation.
+#[derive(Debug, Clone)]
+pub struct CustomerListParams<'a> {
+    inner: ListParams,
+    created_at: CreatedAtFilter,
+    email: Option<&'a str>,
 }
 
-/// A pricing value for a cell within the pricing matrix.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CustomerCostPriceBlockMatrixPriceValue {
-    /// The dimensions corresponding to this cell.
-    pub dimension_values: Vec<Option<String>>,
-    /// The per-unit amount usage within this cell bills.
-    pub unit_amount: String,
+impl<'a> Default for CustomerListParams<'a> {
+    fn default() -> CustomerListParams<'a> {
+        CustomerListParams::DEFAULT
+    }
 }
 
-/// Unit pricing details.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CustomerCostPriceBlockUnitPrice {
-    /// The Orb-assigned unique identifier for the price block.
-    pub id: String,
-    /// An optional user-defined ID for this price resource.
-    #[serde(rename = "external_price_id")]
-    pub external_id: Option<String>,
-    /// Information about the item being priced.
-    pub item: CustomerCostItem,
-    /// The configuration for this unit price.
-    pub unit_config: CustomerCostPriceBlockUnitPriceConfig,
-}
+impl<'a> CustomerListParams<'a> {
+    /// The default customer list parameters.
+    ///
+    /// Exposed as a constant for use in constant evaluation contexts.
+    pub const DEFAULT: CustomerListParams<'static> = CustomerListParams {
+        inner: ListParams::DEFAULT,
+        created_at: CreatedAtFilter::DEFAULT,
+        email: None,
+    };
 
-/// Configuration for a unit price.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct CustomerCostPriceBlockUnitPriceConfig {
-    /// Per-unit pricing.
-    pub unit_amount: String,
+    /// Sets the page size for the list operation.
+    ///
+    /// See [`ListParams::page_size`].
+    pub const fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Filters the listing to customers created after the specified
+    /// datetime.
+    pub const fn created_at_gt(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.gt = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to customers created on or after the specified
+    /// datetime.
+    pub const fn created_at_gte(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.gte = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to customers created before the specified
+    /// datetime.
+    pub const fn created_at_lt(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.lt = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to customers created on or before the specified
+    /// datetime.
+    pub const fn created_at_lte(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.lte = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to customers with the specified email address.
+    ///
+    /// Email addresses are compared case insensitively. The filter is
+    /// applied client side.
+    pub const fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
 }
 
 impl Client {
-    /// Lists all customers.
+    /// Lists customers as configured by `params`.
     ///
     /// The underlying API call is paginated. The returned stream will fetch
     /// additional pages as it is consumed.
-    pub fn list_customers(
-        &self,
-        params: &ListParams,
-    ) -> impl Stream<Item = Result<Customer, Error>> + '_ {
+    pub fn list_customers<'a>(
+        &'a self,
+        params: &CustomerListParams<'a>,
+    ) -> impl Stream<Item = Result<Customer, Error>> + 'a {
         let req = self.build_request(Method::GET, CUSTOMERS_PATH);
-        self.stream_paginated_request(params, req)
-            .try_filter_map(|res| async {
+        let req = params.created_at.apply(req);
+        let email = params.email;
+        self.stream_paginated_request(&params.inner, req)
+            .try_filter_map(move |res| async move {
                 match res {
-                    CustomerResponse::Normal(c) => Ok(Some(c)),
+                    CustomerResponse::Normal(c) => match email {
+                        Some(email) if !c.email.eq_ignore_ascii_case(email) => Ok(None),
+                        _ => Ok(Some(c)),
+                    },
                     CustomerResponse::Deleted {
                         id: _,
                         deleted: true,
@@ -699,6 +891,17 @@ impl Client {
             })
     }
 
+    /// Finds the first customer with the specified email address.
+    ///
+    /// Orb does not support filtering customers by email address, so this
+    /// method lists customers until it finds a match. Prefer looking up
+    /// customers by ID or external ID when possible.
+    pub async fn find_customer_by_email(&self, email: &str) -> Result<Option<Customer>, Error> {
+        let params = CustomerListParams::DEFAULT.email(email);
+        let mut customers = Box::pin(self.list_customers(&params));
+        customers.try_next().await
+    }
+
     /// Creates a new customer.
     pub async fn create_customer(
         &self,
@@ -820,6 +1023,41 @@ impl Client {
         self.send_request(req).await
     }
 
+    /// Lists the transactions that changed a customer's balance.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn list_balance_transactions(
+        &self,
+        id: &str,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<BalanceTransaction, Error>> + '_ {
+        let req = self.build_request(
+            Method::GET,
+            CUSTOMERS_PATH
+                .chain_one(id)
+                .chain_one("balance_transactions"),
+        );
+        self.stream_paginated_request(params, req)
+    }
+
+    /// Creates a transaction that changes a customer's balance.
+    pub async fn create_balance_transaction(
+        &self,
+        id: &str,
+        transaction: &CreateBalanceTransactionRequest<'_>,
+    ) -> Result<BalanceTransaction, Error> {
+        let req = self.build_request(
+            Method::POST,
+            CUSTOMERS_PATH
+                .chain_one(id)
+                .chain_one("balance_transactions"),
+        );
+        let req = req.json(transaction);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
     /// Fetch a day-by-day snapshot of a customer's costs.
     pub async fn get_customer_costs(
         &self,
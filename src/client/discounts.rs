@@ -0,0 +1,116 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::Amount;
+
+/// A discount applied to a plan, price or subscription.
+///
+/// Discounts whose type is not known to this crate are represented as
+/// [`Discount::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "discount_type", rename_all = "snake_case")]
+pub enum Discount {
+    /// A discount of a percentage of the amount.
+    Percentage(PercentageDiscount),
+    /// A discount of a fixed amount.
+    Amount(AmountDiscount),
+    /// A discount of a number of units of usage.
+    Usage(UsageDiscount),
+    /// A discount applied during a trial.
+    Trial(TrialDiscount),
+    /// A discount with a type that is not known to this crate.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// Details of a [`Discount::Percentage`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PercentageDiscount {
+    /// The fraction of the amount to discount, between 0 and 1.
+    #[serde(with = "crate::money::number")]
+    pub percentage_discount: Amount,
+    /// The IDs of the prices to which the discount applies.
+    #[serde(default)]
+    pub applies_to_price_ids: Vec<String>,
+    /// The reason for the discount.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Details of a [`Discount::Amount`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AmountDiscount {
+    /// The amount to discount.
+    pub amount_discount: Amount,
+    /// The IDs of the prices to which the discount applies.
+    #[serde(default)]
+    pub applies_to_price_ids: Vec<String>,
+    /// The reason for the discount.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Details of a [`Discount::Usage`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UsageDiscount {
+    /// The number of units of usage to discount.
+    #[serde(with = "crate::money::number")]
+    pub usage_discount: Amount,
+    /// The IDs of the prices to which the discount applies.
+    #[serde(default)]
+    pub applies_to_price_ids: Vec<String>,
+    /// The reason for the discount.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Details of a [`Discount::Trial`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TrialDiscount {
+    /// The amount to discount during the trial.
+    #[serde(default)]
+    pub trial_amount_discount: Option<Amount>,
+    /// The percentage of the amount to discount during the trial.
+    #[serde(default, with = "crate::money::number::option")]
+    pub trial_percentage_discount: Option<Amount>,
+    /// The IDs of the prices to which the discount applies.
+    #[serde(default)]
+    pub applies_to_price_ids: Vec<String>,
+    /// The reason for the discount.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// A minimum amount billed for a plan or set of prices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Minimum {
+    /// The minimum amount.
+    pub minimum_amount: Amount,
+    /// The IDs of the prices to which the minimum applies.
+    #[serde(default)]
+    pub applies_to_price_ids: Vec<String>,
+}
+
+/// A maximum amount billed for a plan or set of prices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Maximum {
+    /// The maximum amount.
+    pub maximum_amount: Amount,
+    /// The IDs of the prices to which the maximum applies.
+    #[serde(default)]
+    pub applies_to_price_ids: Vec<String>,
+}
@@ -14,17 +14,22 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt;
 
 use futures_core::Stream;
+use futures_util::future;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Number;
-use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
 
 use crate::client::customers::CustomerId;
 use crate::client::Client;
 use crate::config::ListParams;
 use crate::error::Error;
+use crate::money::Amount;
 use crate::util::StrIteratorExt;
 
 const EVENTS_PATH: [&str; 1] = ["events"];
@@ -113,9 +118,186 @@ pub struct Event {
     /// Important for attributing usage to a given billing period.
     #[serde(with = "time::serde::rfc3339")]
     pub timestamp: OffsetDateTime,
+    /// Whether the event has been deprecated.
+    #[serde(default)]
+    pub deprecated: bool,
+}
+
+/// A strongly typed Orb event.
+///
+/// Implementations map the fields of a Rust type to the properties of an Orb
+/// event. Rather than implementing this trait by hand, enable the `derive`
+/// feature and use `#[derive(OrbEvent)]`:
+///
+/// ```ignore
+/// use orb_billing::OrbEvent;
+///
+/// #[derive(OrbEvent)]
+/// #[orb(event_name = "compute_usage")]
+/// struct ComputeUsage {
+///     cluster_id: String,
+///     #[orb(rename = "credits")]
+///     credits_used: f64,
+///     region: Option<String>,
+/// }
+/// ```
+///
+/// Each field must implement [`EventProperty`].
+pub trait OrbEvent: Sized {
+    /// The name of the event, as used in [`IngestEventRequest::event_name`].
+    const EVENT_NAME: &'static str;
+
+    /// Converts the event into its properties.
+    fn to_properties(&self) -> BTreeMap<String, EventPropertyValue>;
+
+    /// Reconstructs the event from its properties.
+    ///
+    /// Returns [`Error::UnexpectedResponse`] if a property is missing or has
+    /// an unexpected type.
+    fn from_properties(properties: &BTreeMap<String, EventPropertyValue>) -> Result<Self, Error>;
+
+    /// Reconstructs the event from an [`Event`] returned by
+    /// [`Client::search_events`].
+    ///
+    /// Returns [`Error::UnexpectedResponse`] if the event's name does not
+    /// match [`OrbEvent::EVENT_NAME`].
+    fn from_event(event: &Event) -> Result<Self, Error> {
+        if event.event_name != Self::EVENT_NAME {
+            return Err(Error::UnexpectedResponse {
+                detail: format!(
+                    "event {} has name {:?} but expected {:?}",
+                    event.id,
+                    event.event_name,
+                    Self::EVENT_NAME
+                ),
+            });
+        }
+        Self::from_properties(&event.properties)
+    }
+
+    /// Constructs an ingestion request for the event.
+    ///
+    /// The event's properties are written into `properties`, which the
+    /// returned request borrows.
+    fn to_ingest_request<'a>(
+        &self,
+        idempotency_key: &'a str,
+        customer_id: CustomerId<'a>,
+        timestamp: OffsetDateTime,
+        properties: &'a mut BTreeMap<String, EventPropertyValue>,
+    ) -> IngestEventRequest<'a> {
+        *properties = self.to_properties();
+        IngestEventRequest {
+            idempotency_key,
+            customer_id,
+            event_name: Self::EVENT_NAME,
+            properties,
+            timestamp,
+        }
+    }
+}
+
+/// A type that can be stored in a property of an [`OrbEvent`].
+pub trait EventProperty: Sized {
+    /// Converts the value into an [`EventPropertyValue`].
+    ///
+    /// Returns `None` if the property should be omitted from the event.
+    fn to_property_value(&self) -> Option<EventPropertyValue>;
+
+    /// Converts an [`EventPropertyValue`] into a value of this type.
+    ///
+    /// The `value` is `None` if the property is absent from the event.
+    /// Returns `None` if the value cannot be represented by this type.
+    fn from_property_value(value: Option<&EventPropertyValue>) -> Option<Self>;
+}
+
+impl EventProperty for String {
+    fn to_property_value(&self) -> Option<EventPropertyValue> {
+        Some(EventPropertyValue::String(self.clone()))
+    }
+
+    fn from_property_value(value: Option<&EventPropertyValue>) -> Option<Self> {
+        match value {
+            Some(EventPropertyValue::String(s)) => Some(s.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl EventProperty for bool {
+    fn to_property_value(&self) -> Option<EventPropertyValue> {
+        Some(EventPropertyValue::Bool(*self))
+    }
+
+    fn from_property_value(value: Option<&EventPropertyValue>) -> Option<Self> {
+        match value {
+            Some(EventPropertyValue::Bool(b)) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_event_property_int {
+    ($($ty:ty => $as:ident),*) => {
+        $(
+            impl EventProperty for $ty {
+                fn to_property_value(&self) -> Option<EventPropertyValue> {
+                    Some(EventPropertyValue::Number(Number::from(*self)))
+                }
+
+                fn from_property_value(value: Option<&EventPropertyValue>) -> Option<Self> {
+                    match value {
+                        Some(EventPropertyValue::Number(n)) => n.$as()?.try_into().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_event_property_int!(
+    i8 => as_i64, i16 => as_i64, i32 => as_i64, i64 => as_i64,
+    u8 => as_u64, u16 => as_u64, u32 => as_u64, u64 => as_u64
+);
+
+/// Non-finite values cannot be represented in JSON and are omitted from the
+/// event.
+impl EventProperty for f64 {
+    fn to_property_value(&self) -> Option<EventPropertyValue> {
+        Number::from_f64(*self).map(EventPropertyValue::Number)
+    }
+
+    fn from_property_value(value: Option<&EventPropertyValue>) -> Option<Self> {
+        match value {
+            Some(EventPropertyValue::Number(n)) => n.as_f64(),
+            _ => None,
+        }
+    }
+}
+
+/// Absent and null properties are represented as `None`.
+impl<T> EventProperty for Option<T>
+where
+    T: EventProperty,
+{
+    fn to_property_value(&self) -> Option<EventPropertyValue> {
+        self.as_ref().and_then(T::to_property_value)
+    }
+
+    fn from_property_value(value: Option<&EventPropertyValue>) -> Option<Self> {
+        match value {
+            None | Some(EventPropertyValue::Null) => Some(None),
+            Some(value) => T::from_property_value(Some(value)).map(Some),
+        }
+    }
 }
 
 /// The value of a property assocaited with an [`Event`].
+///
+/// Orb only accepts string, numeric and boolean values at ingestion time. The
+/// remaining variants exist so that events ingested by other tooling can
+/// still be deserialized from search results.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum EventPropertyValue {
@@ -125,6 +307,54 @@ pub enum EventPropertyValue {
     Number(Number),
     /// A boolean value.
     Bool(bool),
+    /// A null value.
+    Null,
+    /// An array value.
+    Array(Vec<EventPropertyValue>),
+    /// An object value.
+    Object(BTreeMap<String, EventPropertyValue>),
+}
+
+impl EventPropertyValue {
+    /// Reports whether Orb accepts this value in event ingestion and
+    /// amendment requests.
+    pub fn is_ingestible(&self) -> bool {
+        match self {
+            EventPropertyValue::String(_)
+            | EventPropertyValue::Number(_)
+            | EventPropertyValue::Bool(_) => true,
+            EventPropertyValue::Null
+            | EventPropertyValue::Array(_)
+            | EventPropertyValue::Object(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for EventPropertyValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventPropertyValue::String(s) => f.write_str(s),
+            EventPropertyValue::Number(n) => write!(f, "{n}"),
+            EventPropertyValue::Bool(b) => write!(f, "{b}"),
+            EventPropertyValue::Null => f.write_str("null"),
+            value => match serde_json::to_string(value) {
+                Ok(json) => f.write_str(&json),
+                Err(_) => Err(fmt::Error),
+            },
+        }
+    }
+}
+
+fn validate_properties(properties: &BTreeMap<String, EventPropertyValue>) -> Result<(), Error> {
+    match properties.iter().find(|(_, value)| !value.is_ingestible()) {
+        None => Ok(()),
+        Some((name, value)) => Err(Error::InvalidRequest {
+            detail: format!(
+                "event property {name:?} has value {value:?}, \
+                but only string, numeric and boolean values are accepted"
+            ),
+        }),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
@@ -133,12 +363,45 @@ struct EventFilter<'a> {
     event_ids: Option<&'a [&'a str]>,
     #[serde(skip_serializing_if = "Option::is_none")]
     invoice_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(flatten)]
+    customer_id: Option<CustomerId<'a>>,
     #[serde(with = "time::serde::rfc3339::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
     timeframe_start: Option<OffsetDateTime>,
     #[serde(with = "time::serde::rfc3339::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
     timeframe_end: Option<OffsetDateTime>,
+    // Orb does not support the following filters, so they are applied to
+    // the search results as they are received.
+    #[serde(skip)]
+    event_names: Option<&'a [&'a str]>,
+    #[serde(skip)]
+    properties: &'a [(&'a str, EventPropertyValue)],
+    #[serde(skip)]
+    include_deprecated: bool,
+}
+
+impl EventFilter<'_> {
+    fn matches(&self, event: &Event) -> bool {
+        let customer_matches = match &self.customer_id {
+            None => true,
+            Some(CustomerId::Orb(id)) => event.customer_id == *id,
+            Some(CustomerId::External(id)) => event.external_customer_id.as_deref() == Some(*id),
+        };
+        let event_name_matches = match self.event_names {
+            None => true,
+            Some(names) => names.contains(&&*event.event_name),
+        };
+        let properties_match = self
+            .properties
+            .iter()
+            .all(|(name, value)| event.properties.get(*name) == Some(value));
+        customer_matches
+            && event_name_matches
+            && properties_match
+            && (self.include_deprecated || !event.deprecated)
+    }
 }
 
 /// Parameters for an event search operation.
@@ -163,8 +426,12 @@ impl<'a> EventSearchParams<'a> {
         filter: EventFilter {
             event_ids: None,
             invoice_id: None,
+            customer_id: None,
             timeframe_start: None,
             timeframe_end: None,
+            event_names: None,
+            properties: &[],
+            include_deprecated: true,
         },
     };
 
@@ -188,6 +455,43 @@ impl<'a> EventSearchParams<'a> {
         self
     }
 
+    /// Filters the search to the specified customer.
+    pub const fn customer_id(mut self, filter: CustomerId<'a>) -> Self {
+        self.filter.customer_id = Some(filter);
+        self
+    }
+
+    /// Filters the search to events with any of the specified names.
+    ///
+    /// Orb does not support this filter, so it is applied client side. The
+    /// search still fetches every event that matches the other filters.
+    pub const fn event_names(mut self, filter: &'a [&'a str]) -> Self {
+        self.filter.event_names = Some(filter);
+        self
+    }
+
+    /// Filters the search to events whose properties are equal to all of the
+    /// specified values.
+    ///
+    /// Orb does not support this filter, so it is applied client side. The
+    /// search still fetches every event that matches the other filters.
+    pub const fn properties(mut self, filter: &'a [(&'a str, EventPropertyValue)]) -> Self {
+        self.filter.properties = filter;
+        self
+    }
+
+    /// Sets whether deprecated events returned by Orb are included in the
+    /// search.
+    ///
+    /// Deprecated events are included by default. Orb does not support this
+    /// filter, so it is applied client side: excluding deprecated events
+    /// removes them from the results, but including them cannot surface
+    /// deprecated events that Orb omits.
+    pub const fn include_deprecated(mut self, include_deprecated: bool) -> Self {
+        self.filter.include_deprecated = include_deprecated;
+        self
+    }
+
     /// Filters the search to events falling on or after the specified datetime.
     pub const fn timeframe_start(mut self, start: OffsetDateTime) -> Self {
         self.filter.timeframe_start = Some(start);
@@ -201,18 +505,345 @@ impl<'a> EventSearchParams<'a> {
     }
 }
 
+/// Parameters for an event volume query.
+#[derive(Debug, Clone)]
+pub struct EventVolumeParams {
+    inner: ListParams,
+    timeframe_start: OffsetDateTime,
+    timeframe_end: Option<OffsetDateTime>,
+}
+
+impl EventVolumeParams {
+    /// Constructs parameters for an event volume query that covers the
+    /// timeframe starting at the specified datetime.
+    pub const fn new(timeframe_start: OffsetDateTime) -> EventVolumeParams {
+        EventVolumeParams {
+            inner: ListParams::DEFAULT,
+            timeframe_start,
+            timeframe_end: None,
+        }
+    }
+
+    /// Sets the page size for the list operation.
+    ///
+    /// See [`ListParams::page_size`].
+    pub const fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Limits the query to the timeframe before the specified datetime.
+    ///
+    /// If unspecified, defaults to the current time.
+    pub const fn timeframe_end(mut self, end: OffsetDateTime) -> Self {
+        self.timeframe_end = Some(end);
+        self
+    }
+}
+
+/// The number of events ingested in a time bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct EventVolume {
+    /// The number of events whose timestamp falls in the bucket.
+    pub count: u64,
+    /// The start of the bucket.
+    #[serde(with = "time::serde::rfc3339")]
+    pub timeframe_start: OffsetDateTime,
+    /// The end of the bucket.
+    #[serde(with = "time::serde::rfc3339")]
+    pub timeframe_end: OffsetDateTime,
+}
+
+/// Identifies a group of events in an [`EventAggregator`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventAggregationKey {
+    /// The name shared by the events in the group.
+    pub event_name: String,
+    /// The Orb ID of the customer shared by the events in the group.
+    pub customer_id: String,
+    /// The value of the [grouping property] shared by the events in the
+    /// group.
+    ///
+    /// `None` if no grouping property is configured or the events do not
+    /// have the property.
+    ///
+    /// [grouping property]: EventAggregator::group_by
+    pub group: Option<EventAggregationGroup>,
+}
+
+/// The value of the grouping property shared by a group of events in an
+/// [`EventAggregator`].
+///
+/// Values of different types are never grouped together, so that, e.g., the
+/// string `"1"` and the number `1` form separate groups. Numbers are grouped
+/// by value, so that, e.g., `1` and `1.0` form the same group.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventAggregationGroup {
+    /// A string value.
+    String(String),
+    /// A numeric value.
+    Number(Amount),
+    /// A boolean value.
+    Bool(bool),
+    /// Any other value, including numbers that cannot be represented as an
+    /// [`Amount`], rendered as JSON.
+    Other(String),
+}
+
+impl From<&EventPropertyValue> for EventAggregationGroup {
+    fn from(value: &EventPropertyValue) -> EventAggregationGroup {
+        match value {
+            EventPropertyValue::String(s) => EventAggregationGroup::String(s.clone()),
+            EventPropertyValue::Number(n) => match n.to_string().parse() {
+                Ok(amount) => EventAggregationGroup::Number(amount),
+                Err(_) => EventAggregationGroup::Other(n.to_string()),
+            },
+            EventPropertyValue::Bool(b) => EventAggregationGroup::Bool(*b),
+            value => EventAggregationGroup::Other(value.to_string()),
+        }
+    }
+}
+
+/// Aggregate statistics for a group of events in an [`EventAggregator`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct EventAggregate {
+    /// The number of events in the group.
+    pub count: u64,
+    /// The sum of the [value property] over the events in the group.
+    ///
+    /// Events without a numeric value property do not contribute to the
+    /// sum.
+    ///
+    /// [value property]: EventAggregator::value_property
+    pub sum: Amount,
+    /// The maximum of the [value property] over the events in the group.
+    ///
+    /// `None` if no event in the group has a numeric value property.
+    ///
+    /// [value property]: EventAggregator::value_property
+    pub max: Option<Amount>,
+}
+
+/// Aggregates [`Event`]s by name, customer and an optional property.
+///
+/// Useful for reconciling the events that were emitted against the events
+/// that Orb received, as returned by [`Client::search_events`].
+#[derive(Debug, Clone, Default)]
+pub struct EventAggregator {
+    group_by: Option<String>,
+    value_property: Option<String>,
+    groups: BTreeMap<EventAggregationKey, EventAggregate>,
+}
+
+impl EventAggregator {
+    /// Constructs a new, empty aggregator.
+    pub fn new() -> EventAggregator {
+        EventAggregator::default()
+    }
+
+    /// Additionally groups events by the value of the specified property.
+    pub fn group_by(mut self, property: &str) -> Self {
+        self.group_by = Some(property.into());
+        self
+    }
+
+    /// Sets the numeric property whose sum and maximum are computed for
+    /// each group.
+    pub fn value_property(mut self, property: &str) -> Self {
+        self.value_property = Some(property.into());
+        self
+    }
+
+    /// Adds an event to the aggregation.
+    pub fn add(&mut self, event: &Event) {
+        let key = EventAggregationKey {
+            event_name: event.event_name.clone(),
+            customer_id: event.customer_id.clone(),
+            group: self
+                .group_by
+                .as_ref()
+                .and_then(|property| event.properties.get(property))
+                .map(EventAggregationGroup::from),
+        };
+        let value = self
+            .value_property
+            .as_ref()
+            .and_then(|property| event.properties.get(property))
+            .and_then(|value| match value {
+                EventPropertyValue::Number(n) => n.to_string().parse::<Amount>().ok(),
+                _ => None,
+            });
+        let aggregate = self.groups.entry(key).or_default();
+        aggregate.count += 1;
+        if let Some(value) = value {
+            aggregate.sum += value;
+            aggregate.max = Some(aggregate.max.map_or(value, |max| max.max(value)));
+        }
+    }
+
+    /// Returns the aggregate statistics for each group of events.
+    pub fn groups(&self) -> &BTreeMap<EventAggregationKey, EventAggregate> {
+        &self.groups
+    }
+
+    /// Consumes the aggregator, returning the aggregate statistics for each
+    /// group of events.
+    pub fn into_groups(self) -> BTreeMap<EventAggregationKey, EventAggregate> {
+        self.groups
+    }
+}
+
+impl Extend<Event> for EventAggregator {
+    fn extend<T>(&mut self, events: T)
+    where
+        T: IntoIterator<Item = Event>,
+    {
+        for event in events {
+            self.add(&event);
+        }
+    }
+}
+
+/// Parameters for a bulk event operation.
+///
+/// See [`Client::bulk_deprecate_events`] and [`Client::bulk_amend_events`].
+#[derive(Debug, Clone)]
+pub struct BulkEventParams<'a> {
+    search: EventSearchParams<'a>,
+    concurrency_limit: usize,
+    dry_run: bool,
+}
+
+impl<'a> Default for BulkEventParams<'a> {
+    fn default() -> BulkEventParams<'a> {
+        BulkEventParams::DEFAULT
+    }
+}
+
+impl<'a> BulkEventParams<'a> {
+    /// The default bulk event parameters.
+    ///
+    /// Exposed as a constant for use in constant evaluation contexts.
+    pub const DEFAULT: BulkEventParams<'static> = BulkEventParams {
+        search: EventSearchParams::DEFAULT,
+        concurrency_limit: 16,
+        dry_run: false,
+    };
+
+    /// Sets the search that determines the candidate events.
+    pub const fn search(mut self, search: EventSearchParams<'a>) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Sets the maximum number of concurrent requests to issue.
+    ///
+    /// The default concurrency limit is 16.
+    pub const fn concurrency_limit(mut self, concurrency_limit: usize) -> Self {
+        self.concurrency_limit = concurrency_limit;
+        self
+    }
+
+    /// Sets whether to only report the events that would be affected
+    /// without modifying them.
+    pub const fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+/// A summary of a bulk event operation.
+#[derive(Debug)]
+pub struct BulkEventReport {
+    /// Whether the operation was a dry run.
+    ///
+    /// If `true`, [`BulkEventReport::succeeded`] contains the IDs of the
+    /// events that would have been modified.
+    pub dry_run: bool,
+    /// The number of events returned by the search.
+    pub searched: usize,
+    /// The number of searched events that were not selected for the
+    /// operation.
+    pub skipped: usize,
+    /// The IDs of the events that were successfully modified.
+    pub succeeded: Vec<String>,
+    /// The IDs of the events that could not be modified, along with the
+    /// error that occurred.
+    pub failed: Vec<(String, Error)>,
+}
+
 impl Client {
     /// Searches events.
-    pub fn search_events(
-        &self,
-        params: &EventSearchParams,
-    ) -> impl Stream<Item = Result<Event, Error>> + '_ {
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn search_events<'a>(
+        &'a self,
+        params: &EventSearchParams<'a>,
+    ) -> impl Stream<Item = Result<Event, Error>> + 'a {
         let req = self.build_request(Method::POST, EVENTS_PATH.chain_one("search"));
         let req = req.json(&params.filter);
+        let filter = params.filter.clone();
+        self.stream_paginated_request(&params.inner, req)
+            .try_filter(move |event| future::ready(filter.matches(event)))
+    }
+
+    /// Searches events and aggregates the results with `aggregator`.
+    ///
+    /// Returns the aggregator after all searched events have been added.
+    pub async fn aggregate_events(
+        &self,
+        params: &EventSearchParams<'_>,
+        mut aggregator: EventAggregator,
+    ) -> Result<EventAggregator, Error> {
+        let mut events = Box::pin(self.search_events(params));
+        while let Some(event) = events.try_next().await? {
+            aggregator.add(&event);
+        }
+        Ok(aggregator)
+    }
+
+    /// Lists the number of events ingested per time bucket.
+    ///
+    /// Buckets are an hour long and count events by their timestamp, not
+    /// by the time at which they were ingested. Buckets without events are
+    /// omitted.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn get_event_volume(
+        &self,
+        params: &EventVolumeParams,
+    ) -> impl Stream<Item = Result<EventVolume, Error>> + '_ {
+        let req = self.build_request(Method::GET, EVENTS_PATH.chain_one("volume"));
+        let mut req = req.query(&[(
+            "timeframe_start",
+            params
+                .timeframe_start
+                // Orb requires supplied datetimes be in UTC
+                .to_offset(UtcOffset::UTC)
+                .format(&Rfc3339)
+                .unwrap(),
+        )]);
+        if let Some(timeframe_end) = params.timeframe_end {
+            req = req.query(&[(
+                "timeframe_end",
+                timeframe_end
+                    // Orb requires supplied datetimes be in UTC
+                    .to_offset(UtcOffset::UTC)
+                    .format(&Rfc3339)
+                    .unwrap(),
+            )]);
+        }
         self.stream_paginated_request(&params.inner, req)
     }
 
     /// Ingests events.
+    ///
+    /// Returns [`Error::InvalidRequest`] without contacting Orb if any event
+    /// has a property whose value is not [ingestible].
+    ///
+    /// [ingestible]: EventPropertyValue::is_ingestible
     pub async fn ingest_events(
         &self,
         mode: IngestionMode,
@@ -223,6 +854,10 @@ impl Client {
             events: &'a [IngestEventRequest<'a>],
         }
 
+        for event in events {
+            validate_properties(event.properties)?;
+        }
+
         let req = self.build_request(Method::POST, ["ingest"]);
         let req = req.query(&[("debug", matches!(mode, IngestionMode::Debug))]);
         let req = req.json(&Envelope { events });
@@ -231,13 +866,144 @@ impl Client {
     }
 
     /// Amends an event by ID.
+    ///
+    /// Returns [`Error::InvalidRequest`] without contacting Orb if the event
+    /// has a property whose value is not [ingestible].
+    ///
+    /// [ingestible]: EventPropertyValue::is_ingestible
     pub async fn amend_event(&self, id: &str, event: &AmendEventRequest<'_>) -> Result<(), Error> {
+        validate_properties(event.properties)?;
         let req = self.build_request(Method::PUT, EVENTS_PATH.chain_one(id));
         let req = req.json(event);
         let _: serde_json::Value = self.send_request(req).await?;
         Ok(())
     }
 
+    /// Deprecates all events matching a search and a predicate.
+    ///
+    /// All events returned by the search configured in `params` are
+    /// collected before any event is deprecated. Each event for which
+    /// `predicate` returns `true` is then deprecated, with up to
+    /// [`BulkEventParams::concurrency_limit`] requests in flight at once.
+    ///
+    /// An error is returned only if the search fails. Failures to deprecate
+    /// individual events are recorded in the returned report.
+    pub async fn bulk_deprecate_events<P>(
+        &self,
+        params: &BulkEventParams<'_>,
+        mut predicate: P,
+    ) -> Result<BulkEventReport, Error>
+    where
+        P: FnMut(&Event) -> bool,
+    {
+        self.bulk_event_operation(
+            params,
+            |event| predicate(event),
+            |event| async move {
+                let res = self.deprecate_event(&event.id).await;
+                (event.id, res)
+            },
+        )
+        .await
+    }
+
+    /// Amends all events matching a search.
+    ///
+    /// All events returned by the search configured in `params` are
+    /// collected before any event is amended. Each event is passed to
+    /// `amend`, which may modify the event's customer, name, properties and
+    /// timestamp in place. If `amend` returns `true`, the event is then
+    /// replaced with its modified version, with up to
+    /// [`BulkEventParams::concurrency_limit`] requests in flight at once.
+    ///
+    /// The amended event is associated with the customer identified by its
+    /// `external_customer_id`, if present, or by its `customer_id` otherwise.
+    /// If `amend` changes `customer_id` but leaves `external_customer_id`
+    /// unchanged, the event is associated with the customer identified by
+    /// the new `customer_id`.
+    ///
+    /// An error is returned only if the search fails. Failures to amend
+    /// individual events are recorded in the returned report.
+    pub async fn bulk_amend_events<F>(
+        &self,
+        params: &BulkEventParams<'_>,
+        mut amend: F,
+    ) -> Result<BulkEventReport, Error>
+    where
+        F: FnMut(&mut Event) -> bool,
+    {
+        let select = |event: &mut Event| {
+            let customer_id = event.customer_id.clone();
+            let external_customer_id = event.external_customer_id.clone();
+            let amended = amend(event);
+            if event.customer_id != customer_id
+                && event.external_customer_id == external_customer_id
+            {
+                event.external_customer_id = None;
+            }
+            amended
+        };
+        self.bulk_event_operation(params, select, |event| async move {
+            let customer_id = match &event.external_customer_id {
+                Some(id) => CustomerId::External(id),
+                None => CustomerId::Orb(&event.customer_id),
+            };
+            let res = self
+                .amend_event(
+                    &event.id,
+                    &AmendEventRequest {
+                        customer_id,
+                        event_name: &event.event_name,
+                        properties: &event.properties,
+                        timestamp: event.timestamp,
+                    },
+                )
+                .await;
+            (event.id, res)
+        })
+        .await
+    }
+
+    async fn bulk_event_operation<S, O, F>(
+        &self,
+        params: &BulkEventParams<'_>,
+        mut select: S,
+        op: O,
+    ) -> Result<BulkEventReport, Error>
+    where
+        S: FnMut(&mut Event) -> bool,
+        O: Fn(Event) -> F,
+        F: std::future::Future<Output = (String, Result<(), Error>)>,
+    {
+        let events: Vec<Event> = self.search_events(&params.search).try_collect().await?;
+        let searched = events.len();
+        let selected: Vec<Event> = events
+            .into_iter()
+            .filter_map(|mut event| select(&mut event).then_some(event))
+            .collect();
+        let mut report = BulkEventReport {
+            dry_run: params.dry_run,
+            searched,
+            skipped: searched - selected.len(),
+            succeeded: vec![],
+            failed: vec![],
+        };
+        if params.dry_run {
+            report.succeeded = selected.into_iter().map(|event| event.id).collect();
+            return Ok(report);
+        }
+        let mut results = stream::iter(selected)
+            .map(op)
+            .buffer_unordered(params.concurrency_limit.max(1));
+        while let Some((id, res)) = results.next().await {
+            match res {
+                Ok(()) => report.succeeded.push(id),
+                Err(e) => report.failed.push((id, e)),
+            }
+        }
+        Ok(report)
+    }
+
     /// Deprecates an event by ID.
     pub async fn deprecate_event(&self, id: &str) -> Result<(), Error> {
         let req = self.build_request(
@@ -24,6 +24,7 @@ use crate::client::customers::CustomerId;
 use crate::client::Client;
 use crate::config::ListParams;
 use crate::error::Error;
+use crate::money::{Amount, Money};
 use crate::util::StrIteratorExt;
 
 const INVOICES: [&str; 1] = ["invoices"];
@@ -47,11 +48,11 @@ pub struct Invoice {
     /// An ISO 4217 currency string, or "credits"
     pub currency: String,
     /// The total after any minimums, discounts, and taxes have been applied.
-    pub total: String,
+    pub total: Amount,
     /// This is the final amount required to be charged to the
     /// customer and reflects the application of the customer balance
     /// to the total of the invoice.
-    pub amount_due: String,
+    pub amount_due: Amount,
     /// The time at which the invoice was created.
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
@@ -66,9 +67,50 @@ pub struct Invoice {
     /// values.
     #[serde(default)]
     pub metadata: BTreeMap<String, String>,
+    /// The line items of the invoice.
+    #[serde(default)]
+    pub line_items: Vec<InvoiceLineItem>,
     // TODO: many missing fields.
 }
 
+impl Invoice {
+    /// Returns the invoice's total in the invoice's currency.
+    ///
+    /// Returns `None` if the invoice is denominated in Orb credits.
+    pub fn total_money(&self) -> Option<Money> {
+        Money::from_code(self.total, &self.currency)
+    }
+
+    /// Returns the invoice's amount due in the invoice's currency.
+    ///
+    /// Returns `None` if the invoice is denominated in Orb credits.
+    pub fn amount_due_money(&self) -> Option<Money> {
+        Money::from_code(self.amount_due, &self.currency)
+    }
+}
+
+/// A line item in an [`Invoice`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct InvoiceLineItem {
+    /// The Orb-assigned unique identifier for the line item.
+    pub id: String,
+    /// The name of the line item.
+    pub name: String,
+    /// The amount of the line item, after any discounts and minimums.
+    pub amount: Amount,
+    /// The amount of the line item, before any discounts and minimums.
+    pub subtotal: Amount,
+    /// The number of units billed by the line item.
+    #[serde(with = "crate::money::number")]
+    pub quantity: Amount,
+    /// The start of the period billed by the line item.
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_date: OffsetDateTime,
+    /// The end of the period billed by the line item.
+    #[serde(with = "time::serde::rfc3339")]
+    pub end_date: OffsetDateTime,
+}
+
 /// Identifies the customer associated with an [`Invoice`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct InvoiceCustomer {
@@ -0,0 +1,155 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use futures_core::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use time::OffsetDateTime;
+
+use crate::client::Client;
+use crate::config::ListParams;
+use crate::error::Error;
+use crate::util::StrIteratorExt;
+
+const ITEMS_PATH: [&str; 1] = ["items"];
+
+/// An Orb item.
+///
+/// Items are the products and services that prices and billable metrics
+/// are attached to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Item {
+    /// The Orb-assigned unique identifier for the item.
+    pub id: String,
+    /// The name of the item.
+    pub name: String,
+    /// The time at which the item was created.
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// The connections between the item and entities in external systems.
+    #[serde(default)]
+    pub external_connections: Vec<ItemExternalConnection>,
+    /// Arbitrary metadata attached to the item.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// A connection between an [`Item`] and an entity in an external system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ItemExternalConnection {
+    /// The external system.
+    #[serde(rename = "external_connection_name")]
+    pub name: ItemExternalConnectionName,
+    /// The ID of the entity in the external system.
+    pub external_entity_id: String,
+}
+
+/// The subset of [`ItemExternalConnection`] used in update requests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct ItemExternalConnectionRequest<'a> {
+    /// The external system.
+    #[serde(rename = "external_connection_name")]
+    pub name: ItemExternalConnectionName,
+    /// The ID of the entity in the external system.
+    pub external_entity_id: &'a str,
+}
+
+/// An external system to which an [`Item`] can be connected.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemExternalConnectionName {
+    /// Stripe.
+    Stripe,
+    /// QuickBooks.
+    Quickbooks,
+    /// Bill.com.
+    #[serde(rename = "bill.com")]
+    BillCom,
+    /// NetSuite.
+    Netsuite,
+    /// TaxJar.
+    Taxjar,
+    /// Avalara.
+    Avalara,
+    /// Anrok.
+    Anrok,
+    /// An unknown external system.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The subset of [`Item`] used in create requests.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CreateItemRequest<'a> {
+    /// The name of the item.
+    pub name: &'a str,
+}
+
+/// The subset of [`Item`] used in update requests.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UpdateItemRequest<'a> {
+    /// The name of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    /// The connections between the item and entities in external systems.
+    ///
+    /// Replaces the item's existing external connections.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_connections: Option<&'a [ItemExternalConnectionRequest<'a>]>,
+    /// Arbitrary metadata to attach to the item.
+    ///
+    /// Keys present in the map replace the item's existing metadata. Keys
+    /// mapped to `None` are removed from the item's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, Option<String>>>,
+}
+
+impl Client {
+    /// Lists all items.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn list_items(&self, params: &ListParams) -> impl Stream<Item = Result<Item, Error>> + '_ {
+        let req = self.build_request(Method::GET, ITEMS_PATH);
+        self.stream_paginated_request(params, req)
+    }
+
+    /// Creates a new item.
+    pub async fn create_item(&self, item: &CreateItemRequest<'_>) -> Result<Item, Error> {
+        let req = self.build_request(Method::POST, ITEMS_PATH);
+        let req = req.json(item);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Gets an item by ID.
+    pub async fn get_item(&self, id: &str) -> Result<Item, Error> {
+        let req = self.build_request(Method::GET, ITEMS_PATH.chain_one(id));
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Updates an item by ID.
+    pub async fn update_item(&self, id: &str, item: &UpdateItemRequest<'_>) -> Result<Item, Error> {
+        let req = self.build_request(Method::PUT, ITEMS_PATH.chain_one(id));
+        let req = req.json(item);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use futures_core::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+use crate::client::items::Item;
+use crate::client::Client;
+use crate::config::ListParams;
+use crate::error::Error;
+use crate::util::StrIteratorExt;
+
+const METRICS_PATH: [&str; 1] = ["metrics"];
+
+/// An Orb billable metric.
+///
+/// A billable metric aggregates ingested events into the quantity that a
+/// usage price is billed on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BillableMetric {
+    /// The Orb-assigned unique identifier for the billable metric.
+    pub id: String,
+    /// The name of the billable metric.
+    pub name: String,
+    /// A description of the billable metric.
+    pub description: Option<String>,
+    /// The status of the billable metric.
+    pub status: BillableMetricStatus,
+    /// The item that the billable metric measures.
+    pub item: Item,
+    /// Arbitrary metadata attached to the billable metric.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// The status of a [`BillableMetric`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum BillableMetricStatus {
+    /// The billable metric is active.
+    Active,
+    /// The billable metric is a draft.
+    Draft,
+    /// The billable metric is archived.
+    Archived,
+    /// An unknown status.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The subset of [`BillableMetric`] used in create requests.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct CreateBillableMetricRequest<'a> {
+    /// The name of the billable metric.
+    pub name: &'a str,
+    /// A description of the billable metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    /// The ID of the item that the billable metric measures.
+    pub item_id: &'a str,
+    /// The SQL query that defines the billable metric.
+    ///
+    /// The query is evaluated against the `events` table, e.g.,
+    /// `SELECT sum(duration) FROM events WHERE event_name = 'compute'`.
+    pub sql: &'a str,
+    /// Arbitrary metadata to attach to the billable metric.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, String>>,
+}
+
+/// The subset of [`BillableMetric`] used in update requests.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UpdateBillableMetricRequest<'a> {
+    /// Arbitrary metadata to attach to the billable metric.
+    ///
+    /// Keys present in the map replace the billable metric's existing
+    /// metadata. Keys mapped to `None` are removed from the billable metric's
+    /// metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, Option<String>>>,
+}
+
+impl Client {
+    /// Lists all billable metrics.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn list_billable_metrics(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<BillableMetric, Error>> + '_ {
+        let req = self.build_request(Method::GET, METRICS_PATH);
+        self.stream_paginated_request(params, req)
+    }
+
+    /// Creates a new billable metric.
+    pub async fn create_billable_metric(
+        &self,
+        metric: &CreateBillableMetricRequest<'_>,
+    ) -> Result<BillableMetric, Error> {
+        let req = self.build_request(Method::POST, METRICS_PATH);
+        let req = req.json(metric);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Gets a billable metric by ID.
+    pub async fn get_billable_metric(&self, id: &str) -> Result<BillableMetric, Error> {
+        let req = self.build_request(Method::GET, METRICS_PATH.chain_one(id));
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Updates a billable metric by ID.
+    pub async fn update_billable_metric(
+        &self,
+        id: &str,
+        metric: &UpdateBillableMetricRequest<'_>,
+    ) -> Result<BillableMetric, Error> {
+        let req = self.build_request(Method::PUT, METRICS_PATH.chain_one(id));
+        let req = req.json(metric);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+}
@@ -15,14 +15,19 @@
 
 use std::collections::BTreeMap;
 
+use codes_iso_4217::CurrencyCode;
 use futures_core::Stream;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use time::OffsetDateTime;
 
+use crate::client::discounts::{Discount, Maximum, Minimum};
+use crate::client::prices::{Price, PriceCadence, PriceModelConfig};
 use crate::client::Client;
-use crate::config::ListParams;
+use crate::config::{CreatedAtFilter, ListParams};
 use crate::error::Error;
+use crate::money::Amount;
 use crate::util::StrIteratorExt;
 
 const PLANS_PATH: [&str; 1] = ["plans"];
@@ -45,7 +50,7 @@ impl<'a> Default for PlanId<'a> {
 }
 
 /// An Orb plan.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Plan {
     /// The Orb-assigned unique identifier for the plan.
     pub id: String,
@@ -66,17 +71,334 @@ pub struct Plan {
     /// Arbitrary metadata that is attached to the plan. Cannot be nested, must have string values.
     #[serde(default)]
     pub metadata: BTreeMap<String, String>,
-    // TODO: many missing fields.
+    /// The prices billed by the plan.
+    #[serde(default)]
+    pub prices: Vec<Price>,
+    /// The phases of the plan, if the plan has phases.
+    #[serde(default)]
+    pub plan_phases: Option<Vec<PlanPhase>>,
+    /// The minimum amount billed for the plan.
+    #[serde(default)]
+    pub minimum: Option<Minimum>,
+    /// The maximum amount billed for the plan.
+    #[serde(default)]
+    pub maximum: Option<Maximum>,
+    /// The discount applied to the plan.
+    #[serde(default)]
+    pub discount: Option<Discount>,
+    /// An ISO 4217 currency string, or "credits", in which the plan's prices
+    /// are denominated.
+    #[serde(default)]
+    pub currency: Option<String>,
+    /// An ISO 4217 currency string in which the plan's invoices are issued.
+    #[serde(default)]
+    pub invoicing_currency: Option<String>,
+    /// Determines the difference between the invoice issue date and the
+    /// date that they are due.
+    #[serde(default)]
+    pub net_terms: Option<i64>,
+    /// Determines the default memo on the plan's invoices.
+    #[serde(default)]
+    pub default_invoice_memo: Option<String>,
+    /// The product that the plan belongs to.
+    #[serde(default)]
+    pub product: Option<PlanProduct>,
+    /// The trial configuration of the plan.
+    #[serde(default)]
+    pub trial_config: Option<PlanTrialConfig>,
+    /// The status of the plan.
+    #[serde(default)]
+    pub status: Option<PlanStatus>,
+    /// The version of the plan.
+    #[serde(default)]
+    pub version: Option<i64>,
+}
+
+impl Plan {
+    /// Returns the cadence at which the plan is billed.
+    ///
+    /// This is the shortest recurring cadence of the plan's prices, or `None`
+    /// if the plan has no prices with a known recurring cadence.
+    pub fn billing_cadence(&self) -> Option<PriceCadence> {
+        self.prices
+            .iter()
+            .filter_map(|price| price.base()?.cadence.as_ref())
+            .filter(|cadence| cadence.months().is_some())
+            .min_by_key(|cadence| cadence.months())
+            .cloned()
+    }
+}
+
+/// A phase of a [`Plan`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PlanPhase {
+    /// The Orb-assigned unique identifier for the plan phase.
+    pub id: String,
+    /// A human-readable name for the plan phase.
+    pub name: String,
+    /// A human-readable description of the plan phase.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The position of the phase within the plan, starting at 1.
+    pub order: i64,
+    /// The length of the phase in units of [`PlanPhase::duration_unit`], or
+    /// `None` if the phase lasts indefinitely.
+    #[serde(default)]
+    pub duration: Option<i64>,
+    /// The unit of [`PlanPhase::duration`].
+    #[serde(default)]
+    pub duration_unit: Option<PlanPhaseDurationUnit>,
+    /// The minimum amount billed during the phase.
+    #[serde(default)]
+    pub minimum: Option<Minimum>,
+    /// The maximum amount billed during the phase.
+    #[serde(default)]
+    pub maximum: Option<Maximum>,
+    /// The discount applied during the phase.
+    #[serde(default)]
+    pub discount: Option<Discount>,
+}
+
+/// The unit of [`PlanPhase::duration`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanPhaseDurationUnit {
+    /// Days.
+    Daily,
+    /// Months.
+    Monthly,
+    /// Quarters.
+    Quarterly,
+    /// Half years.
+    SemiAnnual,
+    /// Years.
+    Annual,
+    /// An unknown duration unit.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The product that a [`Plan`] belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PlanProduct {
+    /// The Orb-assigned unique identifier for the product.
+    pub id: String,
+    /// A human-readable name for the product.
+    pub name: String,
+    /// The time at which the product was created.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+}
+
+/// The trial configuration of a [`Plan`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PlanTrialConfig {
+    /// The length of the trial in units of
+    /// [`PlanTrialConfig::trial_period_unit`], or `None` if the plan has no
+    /// trial.
+    pub trial_period: Option<i64>,
+    /// The unit of [`PlanTrialConfig::trial_period`].
+    pub trial_period_unit: TrialPeriodUnit,
+}
+
+/// The unit of [`PlanTrialConfig::trial_period`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum TrialPeriodUnit {
+    /// Days.
+    Days,
+    /// An unknown trial period unit.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The status of a [`Plan`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    /// A plan to which customers can be subscribed.
+    Active,
+    /// A plan that can no longer be used for new subscriptions.
+    Archived,
+    /// A plan that has not yet been published.
+    Draft,
+    /// An unknown plan status.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The subset of [`Plan`] used in create requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreatePlanRequest<'a> {
+    /// A human-readable name for the plan.
+    pub name: &'a str,
+    /// The currency in which the plan's prices are denominated.
+    pub currency: CurrencyCode,
+    /// The prices billed by the plan.
+    pub prices: Vec<CreatePlanPriceRequest<'a>>,
+    /// An optional user-defined ID for this plan resource, used throughout
+    /// the system as an alias for this plan.
+    #[serde(rename = "external_plan_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<&'a str>,
+    /// Arbitrary metadata to attach to the plan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, String>>,
+    /// Determines the difference between the invoice issue date and the
+    /// date that they are due.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_terms: Option<i64>,
+    /// Determines the default memo on the plan's invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_invoice_memo: Option<&'a str>,
+}
+
+/// A price to create as part of a [`CreatePlanRequest`].
+///
+/// The price is denominated in the plan's currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreatePlanPriceRequest<'a> {
+    /// The name of the price.
+    pub name: &'a str,
+    /// The ID of the item being priced.
+    pub item_id: &'a str,
+    /// The cadence at which the price is billed.
+    pub cadence: PriceCadence,
+    /// The pricing model and its configuration.
+    #[serde(flatten)]
+    pub model: PriceModelConfig,
+    /// An optional user-defined ID for this price resource.
+    #[serde(rename = "external_price_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<&'a str>,
+    /// The ID of the billable metric that usage prices are computed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billable_metric_id: Option<&'a str>,
+    /// The quantity billed for fixed prices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::money::number::option")]
+    pub fixed_price_quantity: Option<Amount>,
+    /// The property used to group the price's line items on invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_grouping_key: Option<&'a str>,
+}
+
+/// The subset of [`Plan`] used in update requests.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UpdatePlanRequest<'a> {
+    /// An optional user-defined ID for this plan resource, used throughout
+    /// the system as an alias for this plan.
+    #[serde(rename = "external_plan_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<&'a str>,
+    /// Arbitrary metadata to attach to the plan.
+    ///
+    /// Keys present in the map replace the plan's existing metadata. Keys
+    /// mapped to `None` are removed from the plan's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, Option<String>>>,
+}
+
+/// Parameters for a plan list operation.
+#[derive(Debug, Clone)]
+pub struct PlanListParams {
+    inner: ListParams,
+    status: Option<PlanStatus>,
+    created_at: CreatedAtFilter,
+}
+
+impl Default for PlanListParams {
+    fn default() -> PlanListParams {
+        PlanListParams::DEFAULT
+    }
+}
+
+impl PlanListParams {
+    /// The default plan list parameters.
+    ///
+    /// Exposed as a constant for use in constant evaluation contexts.
+    pub const DEFAULT: PlanListParams = PlanListParams {
+        inner: ListParams::DEFAULT,
+        status: None,
+        created_at: CreatedAtFilter::DEFAULT,
+    };
+
+    /// Sets the page size for the list operation.
+    ///
+    /// See [`ListParams::page_size`].
+    pub const fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Filters the listing to plans with the specified status.
+    pub fn status(mut self, status: PlanStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Filters the listing to plans created after the specified datetime.
+    pub const fn created_at_gt(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.gt = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to plans created on or after the specified
+    /// datetime.
+    pub const fn created_at_gte(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.gte = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to plans created before the specified datetime.
+    pub const fn created_at_lt(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.lt = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to plans created on or before the specified
+    /// datetime.
+    pub const fn created_at_lte(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.lte = Some(created_at);
+        self
+    }
 }
 
 impl Client {
-    /// Lists all plans.
+    /// Lists plans as configured by `params`.
     ///
     /// The underlying API call is paginated. The returned stream will fetch
     /// additional pages as it is consumed.
-    pub fn list_plans(&self, params: &ListParams) -> impl Stream<Item = Result<Plan, Error>> + '_ {
+    pub fn list_plans(
+        &self,
+        params: &PlanListParams,
+    ) -> impl Stream<Item = Result<Plan, Error>> + '_ {
         let req = self.build_request(Method::GET, PLANS_PATH);
-        self.stream_paginated_request(params, req)
+        let req = match &params.status {
+            None => req,
+            Some(status) => req.query(&[("status", status.to_string())]),
+        };
+        let req = params.created_at.apply(req);
+        self.stream_paginated_request(&params.inner, req)
+    }
+
+    /// Creates a new plan.
+    pub async fn create_plan(&self, plan: &CreatePlanRequest<'_>) -> Result<Plan, Error> {
+        let req = self.build_request(Method::POST, PLANS_PATH);
+        let req = req.json(plan);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Updates a plan by ID.
+    pub async fn update_plan(&self, id: &str, plan: &UpdatePlanRequest<'_>) -> Result<Plan, Error> {
+        let req = self.build_request(Method::PUT, PLANS_PATH.chain_one(id));
+        let req = req.json(plan);
+        let res = self.send_request(req).await?;
+        Ok(res)
     }
 
     /// Gets a plan by ID.
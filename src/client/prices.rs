@@ -0,0 +1,774 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use codes_iso_4217::CurrencyCode;
+use futures_core::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::client::customers::{CustomerCostItem, CustomerId};
+use crate::client::events::EventPropertyValue;
+use crate::client::Client;
+use crate::config::ListParams;
+use crate::error::Error;
+use crate::money::Amount;
+use crate::serde::ArrayResponse;
+use crate::util::StrIteratorExt;
+
+const PRICES_PATH: [&str; 1] = ["prices"];
+
+/// A price ID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum PriceId<'a> {
+    /// An Orb price ID.
+    #[serde(rename = "price_id")]
+    Orb(&'a str),
+    /// An external price ID.
+    #[serde(rename = "external_price_id")]
+    External(&'a str),
+}
+
+impl<'a> Default for PriceId<'a> {
+    fn default() -> PriceId<'a> {
+        PriceId::Orb("")
+    }
+}
+
+/// An Orb price.
+///
+/// Each variant corresponds to one of Orb's pricing models. Prices whose
+/// model is not known to this crate are represented as [`Price::Other`].
+///
+/// See: <https://docs.withorb.com/docs/orb-docs/price-models>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "model_type")]
+pub enum Price {
+    /// A fixed amount per unit of usage.
+    #[serde(rename = "unit")]
+    Unit(UnitPrice),
+    /// A fixed amount per package of units.
+    #[serde(rename = "package")]
+    Package(PackagePrice),
+    /// Sets of unit prices in a one or two-dimensional matrix.
+    #[serde(rename = "matrix")]
+    Matrix(MatrixPrice),
+    /// Unit prices that vary across tiers of usage, applied incrementally.
+    #[serde(rename = "tiered")]
+    Tiered(TieredPrice),
+    /// A percentage, in basis points, of the event's amount.
+    #[serde(rename = "bps")]
+    Bps(BpsPrice),
+    /// Basis point rates that vary across tiers of amounts, applied
+    /// incrementally.
+    #[serde(rename = "tiered_bps")]
+    TieredBps(TieredBpsPrice),
+    /// A unit price determined by the tier that the total usage falls into.
+    #[serde(rename = "bulk")]
+    Bulk(BulkPrice),
+    /// A basis point rate determined by the tier that the total amount falls
+    /// into.
+    #[serde(rename = "bulk_bps")]
+    BulkBps(BulkBpsPrice),
+    /// A total amount determined by the threshold that usage exceeds.
+    #[serde(rename = "threshold_total_amount")]
+    ThresholdTotalAmount(ThresholdTotalAmountPrice),
+    /// Package prices that vary across tiers of usage.
+    #[serde(rename = "tiered_package")]
+    TieredPackage(TieredPackagePrice),
+    /// Tiered prices that are applied separately to each group of usage.
+    #[serde(rename = "grouped_tiered")]
+    GroupedTiered(GroupedTieredPrice),
+    /// A price with a pricing model that is not known to this crate.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl Price {
+    /// Returns the fields common to all pricing models.
+    ///
+    /// Returns `None` for [`Price::Other`].
+    pub fn base(&self) -> Option<&BasePrice> {
+        match self {
+            Price::Unit(price) => Some(&price.base),
+            Price::Package(price) => Some(&price.base),
+            Price::Matrix(price) => Some(&price.base),
+            Price::Tiered(price) => Some(&price.base),
+            Price::Bps(price) => Some(&price.base),
+            Price::TieredBps(price) => Some(&price.base),
+            Price::Bulk(price) => Some(&price.base),
+            Price::BulkBps(price) => Some(&price.base),
+            Price::ThresholdTotalAmount(price) => Some(&price.base),
+            Price::TieredPackage(price) => Some(&price.base),
+            Price::GroupedTiered(price) => Some(&price.base),
+            Price::Other(_) => None,
+        }
+    }
+
+    /// Returns the Orb-assigned unique identifier for the price.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Price::Other(value) => value.get("id").and_then(|id| id.as_str()),
+            price => price.base().map(|base| &*base.id),
+        }
+    }
+
+    /// Returns the user-defined ID for the price, if any.
+    pub fn external_id(&self) -> Option<&str> {
+        match self {
+            Price::Other(value) => value.get("external_price_id").and_then(|id| id.as_str()),
+            price => price.base().and_then(|base| base.external_id.as_deref()),
+        }
+    }
+}
+
+/// The fields common to all [`Price`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BasePrice {
+    /// The Orb-assigned unique identifier for the price.
+    pub id: String,
+    /// An optional user-defined ID for this price resource.
+    #[serde(rename = "external_price_id")]
+    pub external_id: Option<String>,
+    /// The name of the price.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Information about the item being priced.
+    pub item: CustomerCostItem,
+    /// Whether the price is billed based on usage or as a fixed fee.
+    #[serde(default)]
+    pub price_type: Option<PriceType>,
+    /// The cadence at which the price is billed.
+    #[serde(default)]
+    pub cadence: Option<PriceCadence>,
+    /// An ISO 4217 currency string, or "credits".
+    #[serde(default)]
+    pub currency: Option<String>,
+    /// The billable metric that usage prices are computed from.
+    #[serde(default)]
+    pub billable_metric: Option<PriceBillableMetric>,
+    /// The quantity billed for fixed prices.
+    #[serde(default, with = "crate::money::number::option")]
+    pub fixed_price_quantity: Option<Amount>,
+    /// The plan phase in which the price is active, if the price belongs to
+    /// a plan with phases.
+    #[serde(default)]
+    pub plan_phase_order: Option<i64>,
+    /// The minimum amount billed for the price.
+    #[serde(default)]
+    pub minimum_amount: Option<Amount>,
+    /// The maximum amount billed for the price.
+    #[serde(default)]
+    pub maximum_amount: Option<Amount>,
+    /// The rate at which the price's currency is converted to the invoicing
+    /// currency.
+    #[serde(default, with = "crate::money::number::option")]
+    pub conversion_rate: Option<Amount>,
+    /// The time at which the price was created.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub created_at: Option<OffsetDateTime>,
+    /// Arbitrary metadata that is attached to the price. Cannot be nested,
+    /// must have string values.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// Whether a [`Price`] is billed based on usage or as a fixed fee.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceType {
+    /// A price billed based on usage.
+    UsagePrice,
+    /// A price billed as a fixed fee.
+    FixedPrice,
+    /// An unknown price type.
+    #[serde(other)]
+    Other(String),
+}
+
+/// The cadence at which a [`Price`] is billed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceCadence {
+    /// Billed once.
+    OneTime,
+    /// Billed every month.
+    Monthly,
+    /// Billed every three months.
+    Quarterly,
+    /// Billed every six months.
+    SemiAnnual,
+    /// Billed every year.
+    Annual,
+    /// An unknown cadence.
+    #[serde(other)]
+    Other(String),
+}
+
+impl PriceCadence {
+    /// Returns the number of months between billings, or `None` if the
+    /// cadence is not recurring or is unknown.
+    pub fn months(&self) -> Option<u8> {
+        match self {
+            PriceCadence::Monthly => Some(1),
+            PriceCadence::Quarterly => Some(3),
+            PriceCadence::SemiAnnual => Some(6),
+            PriceCadence::Annual => Some(12),
+            PriceCadence::OneTime | PriceCadence::Other(_) => None,
+        }
+    }
+}
+
+/// Identifies the billable metric associated with a [`Price`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PriceBillableMetric {
+    /// The Orb-assigned unique identifier for the billable metric.
+    pub id: String,
+}
+
+/// Unit pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UnitPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this unit price.
+    pub unit_config: UnitConfig,
+}
+
+/// Configuration for a unit price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UnitConfig {
+    /// Per-unit pricing.
+    pub unit_amount: Amount,
+}
+
+/// Package pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PackagePrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this package price.
+    pub package_config: PackageConfig,
+}
+
+/// Configuration for a package price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PackageConfig {
+    /// The amount billed per package.
+    pub package_amount: Amount,
+    /// The number of units in each package.
+    pub package_size: i64,
+}
+
+/// Matrix pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatrixPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this matrix price.
+    pub matrix_config: MatrixConfig,
+}
+
+/// Configuration for a pricing matrix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatrixConfig {
+    /// The fallback unit amount.
+    pub default_unit_amount: Amount,
+    /// A collection of dimensions modeled by the matrix.
+    pub dimensions: Vec<Option<String>>,
+    /// All pricing values configured for the matrix.
+    pub matrix_values: Vec<MatrixValue>,
+}
+
+/// A pricing value for a cell within the pricing matrix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MatrixValue {
+    /// The dimensions corresponding to this cell.
+    pub dimension_values: Vec<Option<String>>,
+    /// The per-unit amount usage within this cell bills.
+    pub unit_amount: Amount,
+}
+
+/// Tiered pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TieredPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this tiered price.
+    pub tiered_config: TieredConfig,
+}
+
+/// Configuration for a tiered price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TieredConfig {
+    /// The tiers, in increasing order of usage.
+    pub tiers: Vec<Tier>,
+}
+
+/// A tier of a [`TieredConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Tier {
+    /// The first unit of usage in the tier.
+    #[serde(with = "crate::money::number")]
+    pub first_unit: Amount,
+    /// The last unit of usage in the tier, or `None` if the tier is
+    /// unbounded.
+    #[serde(default, with = "crate::money::number::option")]
+    pub last_unit: Option<Amount>,
+    /// The per-unit amount billed for usage within the tier.
+    pub unit_amount: Amount,
+}
+
+/// Basis point pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BpsPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this basis point price.
+    pub bps_config: BpsConfig,
+}
+
+/// Configuration for a basis point price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BpsConfig {
+    /// The rate, in basis points, applied to the event's amount.
+    #[serde(with = "crate::money::number")]
+    pub bps: Amount,
+    /// The maximum amount billed per event.
+    pub per_unit_maximum: Option<Amount>,
+}
+
+/// Tiered basis point pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TieredBpsPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this tiered basis point price.
+    pub tiered_bps_config: TieredBpsConfig,
+}
+
+/// Configuration for a tiered basis point price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TieredBpsConfig {
+    /// The tiers, in increasing order of amount.
+    pub tiers: Vec<BpsTier>,
+}
+
+/// A tier of a [`TieredBpsConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BpsTier {
+    /// The minimum amount in the tier.
+    pub minimum_amount: Amount,
+    /// The maximum amount in the tier, or `None` if the tier is unbounded.
+    pub maximum_amount: Option<Amount>,
+    /// The rate, in basis points, applied to amounts within the tier.
+    #[serde(with = "crate::money::number")]
+    pub bps: Amount,
+    /// The maximum amount billed per event.
+    pub per_unit_maximum: Option<Amount>,
+}
+
+/// Bulk pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this bulk price.
+    pub bulk_config: BulkConfig,
+}
+
+/// Configuration for a bulk price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkConfig {
+    /// The tiers, in increasing order of usage.
+    pub tiers: Vec<BulkTier>,
+}
+
+/// A tier of a [`BulkConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkTier {
+    /// The maximum number of units in the tier, or `None` if the tier is
+    /// unbounded.
+    #[serde(default, with = "crate::money::number::option")]
+    pub maximum_units: Option<Amount>,
+    /// The per-unit amount billed for all usage when the total usage falls
+    /// within the tier.
+    pub unit_amount: Amount,
+}
+
+/// Bulk basis point pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkBpsPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this bulk basis point price.
+    pub bulk_bps_config: BulkBpsConfig,
+}
+
+/// Configuration for a bulk basis point price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkBpsConfig {
+    /// The tiers, in increasing order of amount.
+    pub tiers: Vec<BulkBpsTier>,
+}
+
+/// A tier of a [`BulkBpsConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkBpsTier {
+    /// The maximum amount in the tier, or `None` if the tier is unbounded.
+    pub maximum_amount: Option<Amount>,
+    /// The rate, in basis points, applied to all amounts when the total
+    /// amount falls within the tier.
+    #[serde(with = "crate::money::number")]
+    pub bps: Amount,
+    /// The maximum amount billed per event.
+    pub per_unit_maximum: Option<Amount>,
+}
+
+/// Threshold total amount pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ThresholdTotalAmountPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this threshold total amount price.
+    pub threshold_total_amount_config: ThresholdTotalAmountConfig,
+}
+
+/// Configuration for a threshold total amount price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ThresholdTotalAmountConfig {
+    /// The thresholds, in increasing order of usage.
+    pub consumption_table: Vec<ThresholdTotalAmountTier>,
+    /// Whether the total amount is prorated for partial billing periods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+}
+
+/// A threshold of a [`ThresholdTotalAmountConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ThresholdTotalAmountTier {
+    /// The usage at which the threshold begins.
+    pub threshold: Amount,
+    /// The total amount billed when usage reaches the threshold.
+    pub total_amount: Amount,
+}
+
+/// Tiered package pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TieredPackagePrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this tiered package price.
+    pub tiered_package_config: TieredPackageConfig,
+}
+
+/// Configuration for a tiered package price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TieredPackageConfig {
+    /// The number of units in each package.
+    pub package_size: Amount,
+    /// The tiers, in increasing order of usage.
+    pub tiers: Vec<TieredPackageTier>,
+}
+
+/// A tier of a [`TieredPackageConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TieredPackageTier {
+    /// The first unit of usage in the tier.
+    pub tier_lower_bound: Amount,
+    /// The amount billed per package for usage within the tier.
+    pub per_unit: Amount,
+}
+
+/// Grouped tiered pricing details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GroupedTieredPrice {
+    /// The fields common to all prices.
+    #[serde(flatten)]
+    pub base: BasePrice,
+    /// The configuration for this grouped tiered price.
+    pub grouped_tiered_config: GroupedTieredConfig,
+}
+
+/// Configuration for a grouped tiered price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GroupedTieredConfig {
+    /// The event property by which usage is grouped.
+    pub grouping_key: String,
+    /// The tiers, in increasing order of usage, applied to each group.
+    pub tiers: Vec<GroupedTier>,
+}
+
+/// A tier of a [`GroupedTieredConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GroupedTier {
+    /// The first unit of usage in the tier.
+    pub tier_lower_bound: Amount,
+    /// The per-unit amount billed for usage within the tier.
+    pub unit_amount: Amount,
+}
+
+/// The pricing model and configuration of a price in create requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "model_type", rename_all = "snake_case")]
+pub enum PriceModelConfig {
+    /// A fixed amount per unit of usage.
+    Unit {
+        /// The configuration for the unit price.
+        unit_config: UnitConfig,
+    },
+    /// A fixed amount per package of units.
+    Package {
+        /// The configuration for the package price.
+        package_config: PackageConfig,
+    },
+    /// Sets of unit prices in a one or two-dimensional matrix.
+    Matrix {
+        /// The configuration for the matrix price.
+        matrix_config: MatrixConfig,
+    },
+    /// Unit prices that vary across tiers of usage, applied incrementally.
+    Tiered {
+        /// The configuration for the tiered price.
+        tiered_config: TieredConfig,
+    },
+    /// A percentage, in basis points, of the event's amount.
+    Bps {
+        /// The configuration for the basis point price.
+        bps_config: BpsConfig,
+    },
+    /// Basis point rates that vary across tiers of amounts, applied
+    /// incrementally.
+    TieredBps {
+        /// The configuration for the tiered basis point price.
+        tiered_bps_config: TieredBpsConfig,
+    },
+    /// A unit price determined by the tier that the total usage falls into.
+    Bulk {
+        /// The configuration for the bulk price.
+        bulk_config: BulkConfig,
+    },
+    /// A basis point rate determined by the tier that the total amount falls
+    /// into.
+    BulkBps {
+        /// The configuration for the bulk basis point price.
+        bulk_bps_config: BulkBpsConfig,
+    },
+    /// A total amount determined by the threshold that usage exceeds.
+    ThresholdTotalAmount {
+        /// The configuration for the threshold total amount price.
+        threshold_total_amount_config: ThresholdTotalAmountConfig,
+    },
+    /// Package prices that vary across tiers of usage.
+    TieredPackage {
+        /// The configuration for the tiered package price.
+        tiered_package_config: TieredPackageConfig,
+    },
+    /// Tiered prices that are applied separately to each group of usage.
+    GroupedTiered {
+        /// The configuration for the grouped tiered price.
+        grouped_tiered_config: GroupedTieredConfig,
+    },
+}
+
+impl Price {
+    /// Returns the pricing model and configuration of the price.
+    ///
+    /// Returns `None` for [`Price::Other`].
+    pub fn model_config(&self) -> Option<PriceModelConfig> {
+        Some(match self {
+            Price::Unit(price) => PriceModelConfig::Unit {
+                unit_config: price.unit_config.clone(),
+            },
+            Price::Package(price) => PriceModelConfig::Package {
+                package_config: price.package_config.clone(),
+            },
+            Price::Matrix(price) => PriceModelConfig::Matrix {
+                matrix_config: price.matrix_config.clone(),
+            },
+            Price::Tiered(price) => PriceModelConfig::Tiered {
+                tiered_config: price.tiered_config.clone(),
+            },
+            Price::Bps(price) => PriceModelConfig::Bps {
+                bps_config: price.bps_config.clone(),
+            },
+            Price::TieredBps(price) => PriceModelConfig::TieredBps {
+                tiered_bps_config: price.tiered_bps_config.clone(),
+            },
+            Price::Bulk(price) => PriceModelConfig::Bulk {
+                bulk_config: price.bulk_config.clone(),
+            },
+            Price::BulkBps(price) => PriceModelConfig::BulkBps {
+                bulk_bps_config: price.bulk_bps_config.clone(),
+            },
+            Price::ThresholdTotalAmount(price) => PriceModelConfig::ThresholdTotalAmount {
+                threshold_total_amount_config: price.threshold_total_amount_config.clone(),
+            },
+            Price::TieredPackage(price) => PriceModelConfig::TieredPackage {
+                tiered_package_config: price.tiered_package_config.clone(),
+            },
+            Price::GroupedTiered(price) => PriceModelConfig::GroupedTiered {
+                grouped_tiered_config: price.grouped_tiered_config.clone(),
+            },
+            Price::Other(_) => return None,
+        })
+    }
+}
+
+/// The subset of [`Price`] used in create requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreatePriceRequest<'a> {
+    /// The name of the price.
+    pub name: &'a str,
+    /// The ID of the item being priced.
+    pub item_id: &'a str,
+    /// The cadence at which the price is billed.
+    pub cadence: PriceCadence,
+    /// The currency of the price.
+    pub currency: CurrencyCode,
+    /// The pricing model and its configuration.
+    #[serde(flatten)]
+    pub model: PriceModelConfig,
+    /// An optional user-defined ID for this price resource.
+    #[serde(rename = "external_price_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<&'a str>,
+    /// The ID of the billable metric that usage prices are computed from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billable_metric_id: Option<&'a str>,
+    /// The quantity billed for fixed prices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "crate::money::number::option")]
+    pub fixed_price_quantity: Option<Amount>,
+    /// The property used to group the price's line items on invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_grouping_key: Option<&'a str>,
+    /// Arbitrary metadata to attach to the price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, String>>,
+}
+
+/// Parameters for a price evaluation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EvaluatePriceRequest<'a> {
+    /// The customer whose usage the price is evaluated against.
+    #[serde(flatten)]
+    pub customer_id: CustomerId<'a>,
+    /// The start of the evaluated timeframe, inclusive.
+    #[serde(with = "time::serde::rfc3339")]
+    pub timeframe_start: OffsetDateTime,
+    /// The end of the evaluated timeframe, exclusive.
+    #[serde(with = "time::serde::rfc3339")]
+    pub timeframe_end: OffsetDateTime,
+    /// A filter expression that selects the events to evaluate the price
+    /// against.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<&'a str>,
+    /// Properties or property expressions by which to group the result.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub grouping_keys: &'a [&'a str],
+}
+
+/// The cost of a price for a group of usage, as computed by
+/// [`Client::evaluate_price`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PriceEvaluationGroup {
+    /// The values of the requested grouping keys for the group, in the order
+    /// in which the grouping keys were specified.
+    #[serde(default)]
+    pub grouping_values: Vec<EventPropertyValue>,
+    /// The quantity of usage in the group.
+    #[serde(with = "crate::money::number")]
+    pub quantity: Amount,
+    /// The price's cost for the group.
+    pub amount: Amount,
+}
+
+impl Client {
+    /// Lists all prices.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn list_prices(
+        &self,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<Price, Error>> + '_ {
+        let req = self.build_request(Method::GET, PRICES_PATH);
+        self.stream_paginated_request(params, req)
+    }
+
+    /// Creates a new price.
+    pub async fn create_price(&self, price: &CreatePriceRequest<'_>) -> Result<Price, Error> {
+        let req = self.build_request(Method::POST, PRICES_PATH);
+        let req = req.json(price);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Gets a price by ID.
+    pub async fn get_price(&self, id: &str) -> Result<Price, Error> {
+        let req = self.build_request(Method::GET, PRICES_PATH.chain_one(id));
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Gets a price by external ID.
+    pub async fn get_price_by_external_id(&self, external_id: &str) -> Result<Price, Error> {
+        let req = self.build_request(
+            Method::GET,
+            PRICES_PATH
+                .chain_one("external_price_id")
+                .chain_one(external_id),
+        );
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Computes the cost of a price for a customer's usage over a timeframe.
+    pub async fn evaluate_price(
+        &self,
+        id: &str,
+        params: &EvaluatePriceRequest<'_>,
+    ) -> Result<Vec<PriceEvaluationGroup>, Error> {
+        let req = self.build_request(
+            Method::POST,
+            PRICES_PATH.chain_one(id).chain_one("evaluate"),
+        );
+        let params = EvaluatePriceRequest {
+            // Orb requires supplied datetimes be in UTC
+            timeframe_start: params.timeframe_start.to_offset(UtcOffset::UTC),
+            timeframe_end: params.timeframe_end.to_offset(UtcOffset::UTC),
+            ..params.clone()
+        };
+        let req = req.json(&params);
+        let res: ArrayResponse<PriceEvaluationGroup> = self.send_request(req).await?;
+        Ok(res.data)
+    }
+}
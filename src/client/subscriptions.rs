@@ -13,26 +13,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use futures_core::Stream;
 use futures_util::stream::TryStreamExt;
-use ordered_float::OrderedFloat;
-use reqwest::Method;
-use serde::{Deserialize, Serialize};
+use reqwest::{Method, RequestBuilder};
+use serde::{Deserialize, Serialize, Serializer};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
-use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Month, OffsetDateTime, UtcOffset};
 
-use crate::client::customers::{Customer, CustomerId, CustomerResponse};
+use crate::client::customers::{
+    CostViewMode, Customer, CustomerCostBucket, CustomerCostParams, CustomerId, CustomerResponse,
+    Filterable,
+};
+use crate::client::discounts::Discount;
 use crate::client::marketplaces::ExternalMarketplace;
-use crate::client::plans::{Plan, PlanId};
+use crate::client::plans::{Plan, PlanId, PlanPhase};
+use crate::client::prices::{Price, PriceId, PriceModelConfig};
 use crate::client::Client;
-use crate::config::ListParams;
+use crate::config::{CreatedAtFilter, ListParams};
 use crate::error::Error;
+use crate::money::Amount;
+use crate::serde::{utc_rfc3339, ArrayResponse};
 use crate::util::StrIteratorExt;
 
 const SUBSCRIPTIONS_PATH: [&str; 1] = ["subscriptions"];
 
 /// An Orb subscription.
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct CreateSubscriptionRequest<'a> {
     /// An optional user-defined ID for this customer resource, used throughout
     /// the system as an alias for this customer.
@@ -48,8 +57,27 @@ pub struct CreateSubscriptionRequest<'a> {
     ///
     /// If `None`, defaults to the current date in the customer's timezone.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(with = "time::serde::rfc3339::option")]
+    #[serde(with = "crate::serde::utc_rfc3339::option")]
     pub start_date: Option<OffsetDateTime>,
+    /// The date at which Orb should stop billing for the subscription.
+    ///
+    /// If `None`, the subscription does not end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "crate::serde::utc_rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+    /// The order of the plan phase that the subscription should start in, if
+    /// the plan has phases.
+    ///
+    /// If `None`, the subscription starts in the plan's first phase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_phase_order: Option<i64>,
+    /// The number of days that the subscription's trial lasts, overriding
+    /// the plan's trial configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_duration_days: Option<i64>,
+    /// Overrides of the plan's prices for this subscription.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub price_overrides: Vec<PriceOverrideRequest<'a>>,
     /// The name of the external marketplace that the subscription is attached
     /// to.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,7 +89,7 @@ pub struct CreateSubscriptionRequest<'a> {
     pub align_billing_with_subscription_start_date: Option<bool>,
     /// The subscription's override minimum amount for the plan.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub minimum_amount: Option<&'a str>,
+    pub minimum_amount: Option<Amount>,
     /// The subscription's override minimum amount for the plan.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub net_terms: Option<i64>,
@@ -76,6 +104,18 @@ pub struct CreateSubscriptionRequest<'a> {
     /// If `None`, the value is determined by the plan configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_invoice_memo: Option<&'a str>,
+    /// The redemption code of a coupon to apply to the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon_redemption_code: Option<&'a str>,
+    /// The accrued amount at which Orb issues an invoice before the end of
+    /// the billing period.
+    ///
+    /// If `None`, invoices are only issued at the end of the billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoicing_threshold: Option<Amount>,
+    /// Arbitrary metadata to attach to the subscription.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, String>>,
     /// An idempotency key can ensure that if the same request comes in
     /// multiple times in a 48-hour period, only one makes changes.
     // NOTE: this is passed in a request header, not the body
@@ -83,6 +123,58 @@ pub struct CreateSubscriptionRequest<'a> {
     pub idempotency_key: Option<&'a str>,
 }
 
+/// An override of one of the plan's prices in a
+/// [`CreateSubscriptionRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PriceOverrideRequest<'a> {
+    /// The ID of the price to override.
+    #[serde(rename = "id")]
+    pub price_id: &'a str,
+    /// The pricing model and configuration to use in place of the price's.
+    ///
+    /// The model must match the model of the overridden price. For example,
+    /// a unit price's unit amount is overridden with
+    /// [`PriceModelConfig::Unit`].
+    #[serde(flatten)]
+    pub model: PriceModelConfig,
+    /// The quantity to use in place of the price's fixed price quantity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "crate::money::number::option")]
+    pub fixed_price_quantity: Option<Amount>,
+    /// A discount to apply to the price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<PriceOverrideDiscountRequest>,
+    /// The minimum amount to bill for the price per billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_amount: Option<Amount>,
+    /// The maximum amount to bill for the price per billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_amount: Option<Amount>,
+}
+
+/// The discount in a [`PriceOverrideRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "discount_type", rename_all = "snake_case")]
+pub enum PriceOverrideDiscountRequest {
+    /// A discount of a percentage of the amount.
+    Percentage {
+        /// The fraction of the amount to discount, between 0 and 1.
+        #[serde(with = "crate::money::number")]
+        percentage_discount: Amount,
+    },
+    /// A discount of a fixed amount.
+    Amount {
+        /// The amount to discount.
+        amount_discount: Amount,
+    },
+    /// A discount of a number of units of usage.
+    Usage {
+        /// The number of units of usage to discount.
+        #[serde(with = "crate::money::number")]
+        usage_discount: Amount,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct SubscriptionExternalMarketplaceRequest<'a> {
     /// The kind of the external marketplace.
@@ -93,8 +185,34 @@ pub struct SubscriptionExternalMarketplaceRequest<'a> {
     pub reporting_id: &'a str,
 }
 
+/// The subset of [`Subscription`] used in update requests.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UpdateSubscriptionRequest<'a> {
+    /// Determines the difference between the invoice issue date and the
+    /// date that they are due.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_terms: Option<i64>,
+    /// Determines whether issued invoices for this subscription will
+    /// automatically be charged with the saved payment method on the due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_collection: Option<bool>,
+    /// Determines the default memo on this subscription's invoices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_invoice_memo: Option<&'a str>,
+    /// The accrued amount at which Orb issues an invoice before the end of
+    /// the billing period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoicing_threshold: Option<Amount>,
+    /// Arbitrary metadata to attach to the subscription.
+    ///
+    /// Keys present in the map replace the subscription's existing metadata.
+    /// Keys mapped to `None` are removed from the subscription's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a BTreeMap<String, Option<String>>>,
+}
+
 /// An Orb subscription.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Subscription<C = Customer> {
     /// The Orb-assigned unique identifier for the subscription.
     pub id: String,
@@ -141,6 +259,184 @@ pub struct Subscription<C = Customer> {
     /// The time at which the subscription was created.
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
+    /// The coupon redeemed by the subscription, if any.
+    #[serde(default)]
+    pub redeemed_coupon: Option<SubscriptionRedeemedCoupon>,
+    /// The discounts applied to the subscription, including those granted by
+    /// redeemed coupons.
+    #[serde(default)]
+    pub discount_intervals: Vec<SubscriptionDiscountInterval>,
+    /// The prices billed by the subscription and the periods over which they
+    /// are billed.
+    #[serde(default)]
+    pub price_intervals: Vec<SubscriptionPriceInterval>,
+    /// Arbitrary metadata that is attached to the subscription.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+    /// The subscription's trial.
+    #[serde(default)]
+    pub trial_info: SubscriptionTrialInfo,
+}
+
+impl<C> Subscription<C> {
+    /// Computes the status of the subscription at the specified instant.
+    ///
+    /// Unlike [`Subscription::status`], which reflects the status at the
+    /// time the subscription was fetched, the status is computed locally from
+    /// the subscription's start, end and trial dates. A subscription with an
+    /// end date in the future is considered to be scheduled to end, whether
+    /// it was created with a fixed term or has been cancelled.
+    pub fn status_at(&self, at: OffsetDateTime) -> LifecycleStatus {
+        if at < self.start_date {
+            return LifecycleStatus::Upcoming;
+        }
+        match self.end_date {
+            Some(end_date) if at >= end_date => LifecycleStatus::Ended,
+            _ if self.trial_info.end_date.is_some_and(|end| at < end) => LifecycleStatus::Trialing,
+            Some(_) => LifecycleStatus::ScheduledToEnd,
+            None => LifecycleStatus::Active,
+        }
+    }
+
+    /// Returns the subscription's current billing period, if the
+    /// subscription is currently active.
+    pub fn current_billing_period(&self) -> Option<BillingPeriod> {
+        Some(BillingPeriod {
+            start: self.current_billing_period_start_date?,
+            end: self.current_billing_period_end_date?,
+        })
+    }
+
+    /// Computes the billing period that follows the current billing period.
+    ///
+    /// The next period starts at the end of the current period and lasts for
+    /// one [`Plan::billing_cadence`], but is cut short by the subscription's
+    /// end date. The next period ends on the subscription's billing cycle
+    /// day, clamped to the last day of the month. The billing cycle day is
+    /// taken from the subscription's price intervals when Orb reports them,
+    /// and is otherwise inferred from the current period.
+    ///
+    /// Returns `None` if the subscription has no current billing period,
+    /// ends with the current billing period, or if its plan has no known
+    /// recurring cadence.
+    pub fn next_billing_period(&self) -> Option<BillingPeriod> {
+        let current = self.current_billing_period()?;
+        let months = self.plan.billing_cadence()?.months()?;
+        let start = current.end;
+        let mut end = add_months(start, months, self.billing_cycle_day(&current)?)?;
+        if let Some(end_date) = self.end_date {
+            if end_date <= start {
+                return None;
+            }
+            end = end.min(end_date);
+        }
+        Some(BillingPeriod { start, end })
+    }
+
+    /// Returns the day of the month on which the subscription's billing
+    /// periods end.
+    fn billing_cycle_day(&self, current: &BillingPeriod) -> Option<u8> {
+        let interval = self.price_intervals.iter().find(|interval| {
+            interval.start_date <= current.end
+                && interval.end_date.map_or(true, |end| end > current.end)
+        });
+        if let Some(interval) = interval {
+            return u8::try_from(interval.billing_cycle_day).ok();
+        }
+        // A period that ends on the last day of a month may have been
+        // clamped, in which case the start of the period, which falls in a
+        // longer month, has the true billing cycle day.
+        let end = current.end;
+        if end.day() == days_in_month(end.year(), end.month())? {
+            Some(end.day().max(current.start.day()))
+        } else {
+            Some(end.day())
+        }
+    }
+
+    /// Returns the plan phase that is active, if the subscription's plan has
+    /// phases.
+    pub fn active_plan_phase(&self) -> Option<&PlanPhase> {
+        let order = self.active_plan_phase_order?;
+        self.plan
+            .plan_phases
+            .as_ref()?
+            .iter()
+            .find(|phase| phase.order == order)
+    }
+}
+
+/// The status of a [`Subscription`] at a given instant, as computed by
+/// [`Subscription::status_at`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleStatus {
+    /// The subscription has not yet started.
+    Upcoming,
+    /// The subscription has started and is in its trial.
+    Trialing,
+    /// The subscription has started and has no end date.
+    Active,
+    /// The subscription has started and is scheduled to end, either because
+    /// it has a fixed term or because it has been cancelled.
+    ///
+    /// This is the "cancellation pending" status. It is named for the
+    /// subscription's end date rather than its cancellation because Orb
+    /// reports fixed-term and cancelled subscriptions identically.
+    ScheduledToEnd,
+    /// The subscription has ended.
+    Ended,
+}
+
+impl LifecycleStatus {
+    /// Reports whether the customer is entitled to the subscription's plan,
+    /// i.e., whether the subscription is trialing, active or scheduled to
+    /// end.
+    pub fn is_entitled(&self) -> bool {
+        matches!(
+            self,
+            LifecycleStatus::Trialing | LifecycleStatus::Active | LifecycleStatus::ScheduledToEnd
+        )
+    }
+}
+
+/// A billing period of a [`Subscription`].
+///
+/// The period includes its start and excludes its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct BillingPeriod {
+    /// The start of the billing period.
+    #[serde(with = "time::serde::rfc3339")]
+    pub start: OffsetDateTime,
+    /// The end of the billing period.
+    #[serde(with = "time::serde::rfc3339")]
+    pub end: OffsetDateTime,
+}
+
+impl BillingPeriod {
+    /// Reports whether the billing period contains the specified instant.
+    pub fn contains(&self, at: OffsetDateTime) -> bool {
+        self.start <= at && at < self.end
+    }
+}
+
+/// Adds the specified number of months to a datetime, moving the day to
+/// `day`, clamped to the last day of the resulting month.
+fn add_months(datetime: OffsetDateTime, months: u8, day: u8) -> Option<OffsetDateTime> {
+    let index = datetime.year() * 12 + i32::from(u8::from(datetime.month())) - 1;
+    let index = index + i32::from(months);
+    let year = index.div_euclid(12);
+    let month = Month::try_from(u8::try_from(index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = day.min(days_in_month(year, month)?);
+    let date = Date::from_calendar_date(year, month, day).ok()?;
+    Some(datetime.replace_date(date))
+}
+
+/// Returns the number of days in the specified month.
+fn days_in_month(year: i32, month: Month) -> Option<u8> {
+    (28..=31)
+        .rev()
+        .find(|day| Date::from_calendar_date(year, month, *day).is_ok())
 }
 
 /// The status of an Orb subscription.
@@ -171,7 +467,292 @@ pub struct SubscriptionFixedFee {
     /// The price ID for the fixed fee.
     pub price_id: String,
     /// The quantity of the fixed fee.
-    pub quantity: OrderedFloat<f64>,
+    #[serde(with = "crate::money::number")]
+    pub quantity: Amount,
+}
+
+/// The trial of a [`Subscription`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SubscriptionTrialInfo {
+    /// The date at which the trial ends, if the subscription has a trial.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+}
+
+/// A coupon redeemed by a [`Subscription`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SubscriptionRedeemedCoupon {
+    /// The ID of the redeemed coupon.
+    pub coupon_id: String,
+    /// The date at which the coupon's discount starts.
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_date: OffsetDateTime,
+    /// The date at which the coupon's discount ends.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+}
+
+/// An entry in [`Subscription::discount_intervals`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SubscriptionDiscountInterval {
+    /// The discount.
+    #[serde(flatten)]
+    pub discount: Discount,
+    /// The date at which the discount starts.
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_date: OffsetDateTime,
+    /// The date at which the discount ends.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+}
+
+/// An entry in [`Subscription::price_intervals`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SubscriptionPriceInterval {
+    /// The Orb-assigned unique identifier for the price interval.
+    pub id: String,
+    /// The price billed during the interval.
+    pub price: Price,
+    /// The date at which the interval starts.
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_date: OffsetDateTime,
+    /// The date at which the interval ends, if it ends.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+    /// The day of the month on which the interval's price is billed.
+    pub billing_cycle_day: i64,
+    /// The start of the current billing period, if the interval is currently
+    /// active.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub current_billing_period_start_date: Option<OffsetDateTime>,
+    /// The end of the current billing period, if the interval is currently
+    /// active.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub current_billing_period_end_date: Option<OffsetDateTime>,
+    /// The changes to the quantity of the interval's fixed fee price.
+    #[serde(default)]
+    pub fixed_fee_quantity_transitions: Vec<FixedFeeQuantityTransition>,
+}
+
+/// A change to the quantity of a fixed fee price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct FixedFeeQuantityTransition {
+    /// The ID of the fixed fee price.
+    pub price_id: String,
+    /// The date at which the quantity changes.
+    #[serde(with = "time::serde::rfc3339")]
+    pub effective_date: OffsetDateTime,
+    /// The quantity of the fixed fee from the effective date onwards.
+    #[serde(with = "crate::money::number")]
+    pub quantity: Amount,
+}
+
+/// Parameters for [`Client::update_fixed_fee_quantity`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UpdateFixedFeeQuantityRequest<'a> {
+    /// The ID of the fixed fee price to update.
+    pub price_id: &'a str,
+    /// The new quantity of the fixed fee.
+    #[serde(with = "crate::money::number")]
+    pub quantity: Amount,
+    /// The date at which the new quantity takes effect, when `change_option`
+    /// is [`FixedFeeQuantityChangeOption::EffectiveDate`].
+    ///
+    /// Orb accepts a calendar date in the customer's timezone rather than a
+    /// datetime, so the date is sent as is, e.g., `2024-01-01`. If `None`,
+    /// defaults to the current date in the customer's timezone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<Date>,
+    /// Determines when the new quantity takes effect.
+    ///
+    /// If `None`, defaults to [`FixedFeeQuantityChangeOption::Immediate`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change_option: Option<FixedFeeQuantityChangeOption>,
+}
+
+/// Determines when a fixed fee quantity change takes effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FixedFeeQuantityChangeOption {
+    /// The change takes effect immediately.
+    Immediate,
+    /// The change takes effect at the start of the next billing period.
+    UpcomingInvoice,
+    /// The change takes effect on
+    /// [`UpdateFixedFeeQuantityRequest::effective_date`].
+    EffectiveDate,
+}
+
+/// Parameters for [`Client::update_price_intervals`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct PriceIntervalsRequest<'a> {
+    /// The price intervals to add to the subscription.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub add: &'a [AddPriceIntervalRequest<'a>],
+    /// The existing price intervals to edit.
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub edit: &'a [EditPriceIntervalRequest<'a>],
+}
+
+/// A price interval to add to a subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct AddPriceIntervalRequest<'a> {
+    /// The price to bill during the interval.
+    #[serde(flatten)]
+    pub price_id: PriceId<'a>,
+    /// The date at which the interval starts.
+    pub start_date: PriceIntervalDate,
+    /// The date at which the interval ends.
+    ///
+    /// If `None`, the interval does not end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<PriceIntervalDate>,
+    /// The changes to the quantity of a fixed fee price over the interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_fee_quantity_transitions: Option<&'a [FixedFeeQuantityTransitionRequest]>,
+}
+
+/// Changes to an existing price interval of a subscription.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct EditPriceIntervalRequest<'a> {
+    /// The ID of the price interval to edit.
+    pub price_interval_id: &'a str,
+    /// The new start date of the interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<PriceIntervalDate>,
+    /// The new end date of the interval.
+    ///
+    /// Setting the end date ends the interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<PriceIntervalDate>,
+    /// Replaces the changes to the quantity of the interval's fixed fee
+    /// price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_fee_quantity_transitions: Option<&'a [FixedFeeQuantityTransitionRequest]>,
+}
+
+/// A change to the quantity of a fixed fee price in a price interval request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct FixedFeeQuantityTransitionRequest {
+    /// The date at which the quantity changes.
+    #[serde(with = "crate::serde::utc_rfc3339")]
+    pub effective_date: OffsetDateTime,
+    /// The quantity of the fixed fee from the effective date onwards.
+    #[serde(with = "crate::money::number")]
+    pub quantity: Amount,
+}
+
+/// The start or end of a price interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceIntervalDate {
+    /// A specific date.
+    Date(OffsetDateTime),
+    /// The start of the subscription's current billing period.
+    StartOfTerm,
+    /// The end of the subscription's current billing period.
+    EndOfTerm,
+}
+
+impl Serialize for PriceIntervalDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PriceIntervalDate::Date(date) => utc_rfc3339::serialize(date, serializer),
+            PriceIntervalDate::StartOfTerm => serializer.serialize_str("start_of_term"),
+            PriceIntervalDate::EndOfTerm => serializer.serialize_str("end_of_term"),
+        }
+    }
+}
+
+/// Parameters for [`Client::redeem_coupon`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct RedeemCouponRequest<'a> {
+    /// The ID of the coupon to redeem.
+    pub coupon_id: &'a str,
+    /// Determines when the coupon takes effect.
+    pub change_option: SubscriptionChangeOption,
+    /// The date at which the coupon takes effect, when `change_option` is
+    /// [`SubscriptionChangeOption::RequestedDate`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "crate::serde::utc_rfc3339::option")]
+    pub change_date: Option<OffsetDateTime>,
+}
+
+/// Determines when a change to a subscription takes effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionChangeOption {
+    /// The change takes effect immediately.
+    Immediate,
+    /// The change takes effect at the end of the subscription's current
+    /// term.
+    EndOfSubscriptionTerm,
+    /// The change takes effect on a requested date.
+    RequestedDate,
+}
+
+/// Parameters for [`Client::update_trial`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct UpdateTrialRequest {
+    /// The new end of the trial.
+    pub trial_end_date: TrialEndDate,
+    /// Whether to shift subsequent price and plan changes by the amount that
+    /// the trial was extended or shortened.
+    ///
+    /// If `None`, defaults to `true`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift: Option<bool>,
+}
+
+/// The end of a subscription's trial in an [`UpdateTrialRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrialEndDate {
+    /// The trial ends at a specific date.
+    Date(OffsetDateTime),
+    /// The trial ends immediately.
+    Immediate,
+}
+
+impl Serialize for TrialEndDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TrialEndDate::Date(date) => utc_rfc3339::serialize(date, serializer),
+            TrialEndDate::Immediate => serializer.serialize_str("immediate"),
+        }
+    }
+}
+
+/// An entry in a subscription's schedule of plans.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SubscriptionScheduleEntry {
+    /// The plan assigned to the subscription.
+    pub plan: SubscriptionSchedulePlan,
+    /// The date at which the plan assignment starts.
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_date: OffsetDateTime,
+    /// The date at which the plan assignment ends, if it ends.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_date: Option<OffsetDateTime>,
+    /// The time at which the plan assignment was created.
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Identifies the plan in a [`SubscriptionScheduleEntry`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SubscriptionSchedulePlan {
+    /// The Orb-assigned unique identifier for the plan.
+    pub id: Option<String>,
+    /// An optional user-defined ID for the plan.
+    #[serde(rename = "external_plan_id")]
+    pub external_id: Option<String>,
+    /// The name of the plan.
+    pub name: Option<String>,
 }
 
 /// Parameters for a subscription list operation.
@@ -179,6 +760,9 @@ pub struct SubscriptionFixedFee {
 pub struct SubscriptionListParams<'a> {
     inner: ListParams,
     filter: Option<CustomerId<'a>>,
+    status: Option<SubscriptionStatus>,
+    plan_id: Option<PlanId<'a>>,
+    created_at: CreatedAtFilter,
 }
 
 impl<'a> Default for SubscriptionListParams<'a> {
@@ -194,6 +778,9 @@ impl<'a> SubscriptionListParams<'a> {
     pub const DEFAULT: SubscriptionListParams<'static> = SubscriptionListParams {
         inner: ListParams::DEFAULT,
         filter: None,
+        status: None,
+        plan_id: None,
+        created_at: CreatedAtFilter::DEFAULT,
     };
 
     /// Sets the page size for the list operation.
@@ -209,6 +796,188 @@ impl<'a> SubscriptionListParams<'a> {
         self.filter = Some(filter);
         self
     }
+
+    /// Filters the listing to subscriptions with the specified status.
+    pub fn status(mut self, status: SubscriptionStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Filters the listing to subscriptions to the specified plan.
+    pub const fn plan_id(mut self, plan_id: PlanId<'a>) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    /// Filters the listing to subscriptions created after the specified
+    /// datetime.
+    pub const fn created_at_gt(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.gt = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to subscriptions created on or after the
+    /// specified datetime.
+    pub const fn created_at_gte(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.gte = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to subscriptions created before the specified
+    /// datetime.
+    pub const fn created_at_lt(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.lt = Some(created_at);
+        self
+    }
+
+    /// Filters the listing to subscriptions created on or before the
+    /// specified datetime.
+    pub const fn created_at_lte(mut self, created_at: OffsetDateTime) -> Self {
+        self.created_at.lte = Some(created_at);
+        self
+    }
+}
+
+/// Parameters for a subscription usage query.
+#[derive(Debug, Clone)]
+pub struct SubscriptionUsageParams<'a> {
+    inner: ListParams,
+    timeframe_start: Option<&'a OffsetDateTime>,
+    timeframe_end: Option<&'a OffsetDateTime>,
+    granularity: Option<UsageGranularity>,
+    view_mode: Option<CostViewMode>,
+    billable_metric_id: Option<&'a str>,
+    group_by: Option<&'a str>,
+}
+
+impl<'a> Default for SubscriptionUsageParams<'a> {
+    fn default() -> SubscriptionUsageParams<'a> {
+        SubscriptionUsageParams::DEFAULT
+    }
+}
+
+impl<'a> SubscriptionUsageParams<'a> {
+    /// The default subscription usage parameters.
+    ///
+    /// Exposed as a constant for use in constant evaluation contexts.
+    pub const DEFAULT: SubscriptionUsageParams<'static> = SubscriptionUsageParams {
+        inner: ListParams::DEFAULT,
+        timeframe_start: None,
+        timeframe_end: None,
+        granularity: None,
+        view_mode: None,
+        billable_metric_id: None,
+        group_by: None,
+    };
+
+    /// Sets the page size for the query.
+    ///
+    /// Only grouped usage is paginated. See [`ListParams::page_size`].
+    pub const fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// The start of the returned range. If not specified this defaults to the
+    /// billing period start date.
+    pub const fn timeframe_start(mut self, timeframe_start: &'a OffsetDateTime) -> Self {
+        self.timeframe_start = Some(timeframe_start);
+        self
+    }
+
+    /// The end of the returned range. If not specified this defaults to the
+    /// billing period end date.
+    pub const fn timeframe_end(mut self, timeframe_end: &'a OffsetDateTime) -> Self {
+        self.timeframe_end = Some(timeframe_end);
+        self
+    }
+
+    /// The size of the buckets that usage is reported in.
+    pub fn granularity(mut self, granularity: UsageGranularity) -> Self {
+        self.granularity = Some(granularity);
+        self
+    }
+
+    /// Whether usage is reported per bucket or cumulatively since the start
+    /// of the billing period.
+    pub const fn view_mode(mut self, view_mode: CostViewMode) -> Self {
+        self.view_mode = Some(view_mode);
+        self
+    }
+
+    /// Filters the usage to the specified billable metric.
+    pub const fn billable_metric_id(mut self, billable_metric_id: &'a str) -> Self {
+        self.billable_metric_id = Some(billable_metric_id);
+        self
+    }
+
+    /// The event property to group usage by.
+    ///
+    /// Grouping usage requires filtering to a single billable metric with
+    /// [`SubscriptionUsageParams::billable_metric_id`].
+    pub const fn group_by(mut self, group_by: &'a str) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+}
+
+/// The size of the buckets in a [`SubscriptionUsage`] series.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageGranularity {
+    /// Usage is reported per day.
+    Day,
+    /// An unknown granularity.
+    #[serde(other)]
+    Other(String),
+}
+
+/// A series of usage of a billable metric by a subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct SubscriptionUsage {
+    /// The billable metric whose usage is reported.
+    pub billable_metric: UsageBillableMetric,
+    /// The group that the usage belongs to, if the usage was grouped with
+    /// [`SubscriptionUsageParams::group_by`].
+    #[serde(default)]
+    pub metric_group: Option<UsageMetricGroup>,
+    /// Whether usage is reported per bucket or cumulatively.
+    pub view_mode: CostViewMode,
+    /// The usage in each bucket.
+    pub usage: Vec<UsageBucket>,
+}
+
+/// Identifies the billable metric in a [`SubscriptionUsage`] series.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UsageBillableMetric {
+    /// The Orb-assigned unique identifier for the billable metric.
+    pub id: String,
+    /// The name of the billable metric.
+    pub name: String,
+}
+
+/// Identifies the group in a grouped [`SubscriptionUsage`] series.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UsageMetricGroup {
+    /// The event property that usage is grouped by.
+    pub property_key: String,
+    /// The value of the event property for this group.
+    pub property_value: String,
+}
+
+/// An entry in [`SubscriptionUsage::usage`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct UsageBucket {
+    /// The quantity used in the bucket.
+    #[serde(with = "crate::money::number")]
+    pub quantity: Amount,
+    /// The start of the bucket.
+    #[serde(with = "time::serde::rfc3339")]
+    pub timeframe_start: OffsetDateTime,
+    /// The end of the bucket.
+    #[serde(with = "time::serde::rfc3339")]
+    pub timeframe_end: OffsetDateTime,
 }
 
 impl Client {
@@ -226,8 +995,28 @@ impl Client {
             Some(CustomerId::Orb(id)) => req.query(&[("customer_id", id)]),
             Some(CustomerId::External(id)) => req.query(&[("external_customer_id", id)]),
         };
-        self.stream_paginated_request(&params.inner, req)
-            .try_filter_map(|subscription: Subscription<CustomerResponse>| async move {
+        let req = match &params.status {
+            None => req,
+            Some(status) => req.query(&[("status", status.to_string())]),
+        };
+        let req = match params.plan_id {
+            None => req,
+            Some(PlanId::Orb(id)) => req.query(&[("plan_id", id)]),
+            Some(PlanId::External(id)) => req.query(&[("external_plan_id", id)]),
+        };
+        let req = params.created_at.apply(req);
+        self.stream_subscriptions(&params.inner, req)
+    }
+
+    /// Streams the subscriptions returned by a paginated request, skipping
+    /// those whose customer has been deleted.
+    pub(crate) fn stream_subscriptions(
+        &self,
+        params: &ListParams,
+        req: RequestBuilder,
+    ) -> impl Stream<Item = Result<Subscription, Error>> + '_ {
+        self.stream_paginated_request(params, req).try_filter_map(
+            |subscription: Subscription<CustomerResponse>| async move {
                 match subscription.customer {
                     CustomerResponse::Normal(customer) => Ok(Some(Subscription {
                         id: subscription.id,
@@ -246,6 +1035,11 @@ impl Client {
                         auto_collection: subscription.auto_collection,
                         default_invoice_memo: subscription.default_invoice_memo,
                         created_at: subscription.created_at,
+                        redeemed_coupon: subscription.redeemed_coupon,
+                        discount_intervals: subscription.discount_intervals,
+                        price_intervals: subscription.price_intervals,
+                        metadata: subscription.metadata,
+                        trial_info: subscription.trial_info,
                     })),
                     CustomerResponse::Deleted {
                         id: _,
@@ -260,7 +1054,8 @@ impl Client {
                         })
                     }
                 }
-            })
+            },
+        )
     }
 
     /// Creates a new subscription.
@@ -285,5 +1080,183 @@ impl Client {
         Ok(res)
     }
 
+    /// Updates a subscription by ID.
+    pub async fn update_subscription(
+        &self,
+        id: &str,
+        subscription: &UpdateSubscriptionRequest<'_>,
+    ) -> Result<Subscription, Error> {
+        let req = self.build_request(Method::PUT, SUBSCRIPTIONS_PATH.chain_one(id));
+        let req = req.json(subscription);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Gets the usage of a subscription as configured by `params`.
+    ///
+    /// Returns one series per billable metric, or, if usage is grouped, one
+    /// series per group. The underlying API call is paginated for grouped
+    /// usage. The returned stream will fetch additional pages as it is
+    /// consumed.
+    pub fn get_subscription_usage(
+        &self,
+        id: &str,
+        params: &SubscriptionUsageParams,
+    ) -> impl Stream<Item = Result<SubscriptionUsage, Error>> + '_ {
+        let mut req = self.build_request(
+            Method::GET,
+            SUBSCRIPTIONS_PATH.chain_one(id).chain_one("usage"),
+        );
+        for (name, value) in [
+            ("timeframe_start", params.timeframe_start),
+            ("timeframe_end", params.timeframe_end),
+        ] {
+            if let Some(value) = value {
+                req = req.query(&[(
+                    name,
+                    value
+                        // Orb requires supplied datetimes be in UTC
+                        .to_offset(UtcOffset::UTC)
+                        .format(&Rfc3339)
+                        .unwrap(),
+                )]);
+            }
+        }
+        if let Some(granularity) = &params.granularity {
+            req = req.query(&[("granularity", granularity.to_string())]);
+        }
+        if let Some(view_mode) = &params.view_mode {
+            req = req.query(&[("view_mode", view_mode.to_string())]);
+        }
+        if let Some(billable_metric_id) = params.billable_metric_id {
+            req = req.query(&[("billable_metric_id", billable_metric_id)]);
+        }
+        if let Some(group_by) = params.group_by {
+            req = req.query(&[("group_by", group_by)]);
+        }
+        self.stream_paginated_request(&params.inner, req)
+    }
+
+    /// Fetch a day-by-day snapshot of a subscription's costs.
+    pub async fn get_subscription_costs(
+        &self,
+        id: &str,
+        params: &CustomerCostParams<'_>,
+    ) -> Result<Vec<CustomerCostBucket>, Error> {
+        let req = self.build_request(
+            Method::GET,
+            SUBSCRIPTIONS_PATH.chain_one(id).chain_one("costs"),
+        );
+        let req = req.apply(&params.filter);
+        let res: ArrayResponse<CustomerCostBucket> = self.send_request(req).await?;
+        Ok(res.data)
+    }
+
+    /// Lists the past, current and future plans assigned to a subscription.
+    ///
+    /// The underlying API call is paginated. The returned stream will fetch
+    /// additional pages as it is consumed.
+    pub fn get_subscription_schedule(
+        &self,
+        id: &str,
+        params: &ListParams,
+    ) -> impl Stream<Item = Result<SubscriptionScheduleEntry, Error>> + '_ {
+        let req = self.build_request(
+            Method::GET,
+            SUBSCRIPTIONS_PATH.chain_one(id).chain_one("schedule"),
+        );
+        self.stream_paginated_request(params, req)
+    }
+
+    /// Changes the quantity of a fixed fee price on a subscription.
+    pub async fn update_fixed_fee_quantity(
+        &self,
+        id: &str,
+        update: &UpdateFixedFeeQuantityRequest<'_>,
+    ) -> Result<Subscription, Error> {
+        let req = self.build_request(
+            Method::POST,
+            SUBSCRIPTIONS_PATH
+                .chain_one(id)
+                .chain_one("update_fixed_fee_quantity"),
+        );
+        let req = req.json(update);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Adds and edits price intervals on a subscription.
+    ///
+    /// To end a price interval, edit it to set its end date.
+    pub async fn update_price_intervals(
+        &self,
+        id: &str,
+        update: &PriceIntervalsRequest<'_>,
+    ) -> Result<Subscription, Error> {
+        let req = self.build_request(
+            Method::POST,
+            SUBSCRIPTIONS_PATH
+                .chain_one(id)
+                .chain_one("price_intervals"),
+        );
+        let req = req.json(update);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Advances a subscription to the next phase of its plan.
+    ///
+    /// The phase change takes effect on `effective_date`, or, if `None`, on
+    /// the current date in the customer's timezone.
+    pub async fn trigger_phase(
+        &self,
+        id: &str,
+        effective_date: Option<Date>,
+    ) -> Result<Subscription, Error> {
+        #[derive(Serialize)]
+        struct Envelope {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            effective_date: Option<Date>,
+        }
+
+        let req = self.build_request(
+            Method::POST,
+            SUBSCRIPTIONS_PATH.chain_one(id).chain_one("trigger_phase"),
+        );
+        let req = req.json(&Envelope { effective_date });
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Redeems a coupon on an existing subscription.
+    pub async fn redeem_coupon(
+        &self,
+        id: &str,
+        redemption: &RedeemCouponRequest<'_>,
+    ) -> Result<Subscription, Error> {
+        let req = self.build_request(
+            Method::POST,
+            SUBSCRIPTIONS_PATH.chain_one(id).chain_one("redeem_coupon"),
+        );
+        let req = req.json(redemption);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
+    /// Extends, shortens or ends the trial of a subscription.
+    pub async fn update_trial(
+        &self,
+        id: &str,
+        trial: &UpdateTrialRequest,
+    ) -> Result<Subscription, Error> {
+        let req = self.build_request(
+            Method::POST,
+            SUBSCRIPTIONS_PATH.chain_one(id).chain_one("update_trial"),
+        );
+        let req = req.json(trial);
+        let res = self.send_request(req).await?;
+        Ok(res)
+    }
+
     // TODO: cancel and unschedule subscriptions.
 }
@@ -16,7 +16,9 @@
 use std::time::Duration;
 
 use once_cell::sync::Lazy;
-use reqwest::Url;
+use reqwest::{RequestBuilder, Url};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
 
 use crate::Client;
 
@@ -95,3 +97,44 @@ impl ListParams {
         self
     }
 }
+
+/// A filter on the creation time of the objects returned by a list
+/// operation.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct CreatedAtFilter {
+    pub(crate) gt: Option<OffsetDateTime>,
+    pub(crate) gte: Option<OffsetDateTime>,
+    pub(crate) lt: Option<OffsetDateTime>,
+    pub(crate) lte: Option<OffsetDateTime>,
+}
+
+impl CreatedAtFilter {
+    pub(crate) const DEFAULT: CreatedAtFilter = CreatedAtFilter {
+        gt: None,
+        gte: None,
+        lt: None,
+        lte: None,
+    };
+
+    /// Applies the filter to a list request.
+    pub(crate) fn apply(&self, mut req: RequestBuilder) -> RequestBuilder {
+        for (name, value) in [
+            ("created_at[gt]", self.gt),
+            ("created_at[gte]", self.gte),
+            ("created_at[lt]", self.lt),
+            ("created_at[lte]", self.lte),
+        ] {
+            if let Some(value) = value {
+                req = req.query(&[(
+                    name,
+                    value
+                        // Orb requires supplied datetimes be in UTC
+                        .to_offset(UtcOffset::UTC)
+                        .format(&Rfc3339)
+                        .unwrap(),
+                )]);
+            }
+        }
+        req
+    }
+}
@@ -31,6 +31,11 @@ pub enum Error {
         /// Details about the nature of the unexpected response.
         detail: String,
     },
+    /// The request was rejected by the client before being sent to the API.
+    InvalidRequest {
+        /// Details about why the request is invalid.
+        detail: String,
+    },
 }
 
 impl fmt::Display for Error {
@@ -41,6 +46,9 @@ impl fmt::Display for Error {
             Error::UnexpectedResponse { detail } => {
                 write!(f, "orb error: unexpected response: {detail}")
             }
+            Error::InvalidRequest { detail } => {
+                write!(f, "orb error: invalid request: {detail}")
+            }
         }
     }
 }
@@ -39,33 +39,88 @@
 mod client;
 mod config;
 mod error;
+#[warn(missing_debug_implementations, missing_docs)]
+mod money;
 mod serde;
 mod util;
 
+pub use client::catalog::{
+    Catalog, CatalogChange, CatalogChangeset, CatalogItem, CatalogPlan, CatalogPrice,
+    CatalogPriceSpec,
+};
+pub use client::coupons::{Coupon, CouponDiscountRequest, CouponListParams, CreateCouponRequest};
+pub use client::credit_notes::{
+    CreateCreditNoteLineItemRequest, CreateCreditNoteRequest, CreditNote, CreditNoteLineItem,
+    CreditNoteListParams, CreditNoteReason, CreditNoteType,
+};
 pub use client::customers::{
     AddIncrementCreditLedgerEntryRequestParams, AddVoidCreditLedgerEntryRequestParams, Address,
-    AddressRequest, CostViewMode, CreateCustomerRequest, CreditLedgerInvoiceSettingsRequestParams,
-    Customer, CustomerCostBucket, CustomerCostItem, CustomerCostParams, CustomerCostPriceBlock,
-    CustomerCostPriceBlockMatrixPrice, CustomerCostPriceBlockMatrixPriceConfig,
-    CustomerCostPriceBlockMatrixPriceValue, CustomerCostPriceBlockPrice,
-    CustomerCostPriceBlockPriceGroup, CustomerCostPriceBlockUnitPrice,
-    CustomerCostPriceBlockUnitPriceConfig, CustomerCreditBlock, CustomerId,
-    CustomerPaymentProviderRequest, LedgerEntry, LedgerEntryRequest, PaymentProvider,
-    UpdateCustomerRequest, VoidReason,
+    AddressRequest, BalanceTransaction, BalanceTransactionAction, BalanceTransactionCreditNote,
+    BalanceTransactionInvoice, BalanceTransactionType, CostViewMode,
+    CreateBalanceTransactionRequest, CreateCustomerRequest,
+    CreditLedgerInvoiceSettingsRequestParams, Customer, CustomerCostBucket, CustomerCostItem,
+    CustomerCostParams, CustomerCostPriceBlock, CustomerCostPriceBlockPriceGroup,
+    CustomerCreditBlock, CustomerId, CustomerListParams, CustomerPaymentProviderRequest,
+    CustomerReportingConfiguration, CustomerTaxConfigurationRequest, LedgerEntry,
+    LedgerEntryRequest, PaymentProvider, UpdateCustomerRequest, VoidReason,
+};
+pub use client::discounts::{
+    AmountDiscount, Discount, Maximum, Minimum, PercentageDiscount, TrialDiscount, UsageDiscount,
 };
 pub use client::events::{
-    AmendEventRequest, Event, EventPropertyValue, EventSearchParams, IngestEventDebugResponse,
-    IngestEventRequest, IngestEventResponse, IngestionMode,
+    AmendEventRequest, BulkEventParams, BulkEventReport, Event, EventAggregate,
+    EventAggregationGroup, EventAggregationKey, EventAggregator, EventProperty, EventPropertyValue,
+    EventSearchParams, EventVolume, EventVolumeParams, IngestEventDebugResponse,
+    IngestEventRequest, IngestEventResponse, IngestionMode, OrbEvent,
 };
 pub use client::invoices::{
-    Invoice, InvoiceCustomer, InvoiceListParams, InvoiceStatusFilter, InvoiceSubscription,
+    Invoice, InvoiceCustomer, InvoiceLineItem, InvoiceListParams, InvoiceStatusFilter,
+    InvoiceSubscription,
+};
+pub use client::items::{
+    CreateItemRequest, Item, ItemExternalConnection, ItemExternalConnectionName,
+    ItemExternalConnectionRequest, UpdateItemRequest,
 };
 pub use client::marketplaces::ExternalMarketplace;
-pub use client::plans::{Plan, PlanId};
+pub use client::metrics::{
+    BillableMetric, BillableMetricStatus, CreateBillableMetricRequest, UpdateBillableMetricRequest,
+};
+pub use client::plans::{
+    CreatePlanPriceRequest, CreatePlanRequest, Plan, PlanId, PlanListParams, PlanPhase,
+    PlanPhaseDurationUnit, PlanProduct, PlanStatus, PlanTrialConfig, TrialPeriodUnit,
+    UpdatePlanRequest,
+};
+pub use client::prices::{
+    BasePrice, BpsConfig, BpsPrice, BpsTier, BulkBpsConfig, BulkBpsPrice, BulkBpsTier, BulkConfig,
+    BulkPrice, BulkTier, CreatePriceRequest, EvaluatePriceRequest, GroupedTier,
+    GroupedTieredConfig, GroupedTieredPrice, MatrixConfig, MatrixPrice, MatrixValue, PackageConfig,
+    PackagePrice, Price, PriceBillableMetric, PriceCadence, PriceEvaluationGroup, PriceId,
+    PriceModelConfig, PriceType, ThresholdTotalAmountConfig, ThresholdTotalAmountPrice,
+    ThresholdTotalAmountTier, Tier, TieredBpsConfig, TieredBpsPrice, TieredConfig,
+    TieredPackageConfig, TieredPackagePrice, TieredPackageTier, TieredPrice, UnitConfig, UnitPrice,
+};
 pub use client::subscriptions::{
-    CreateSubscriptionRequest, Subscription, SubscriptionListParams, SubscriptionStatus,
+    AddPriceIntervalRequest, BillingPeriod, CreateSubscriptionRequest, EditPriceIntervalRequest,
+    FixedFeeQuantityChangeOption, FixedFeeQuantityTransition, FixedFeeQuantityTransitionRequest,
+    LifecycleStatus, PriceIntervalDate, PriceIntervalsRequest, PriceOverrideDiscountRequest,
+    PriceOverrideRequest, RedeemCouponRequest, Subscription, SubscriptionChangeOption,
+    SubscriptionDiscountInterval, SubscriptionFixedFee, SubscriptionListParams,
+    SubscriptionPriceInterval, SubscriptionRedeemedCoupon, SubscriptionScheduleEntry,
+    SubscriptionSchedulePlan, SubscriptionStatus, SubscriptionTrialInfo, SubscriptionUsage,
+    SubscriptionUsageParams, TrialEndDate, UpdateFixedFeeQuantityRequest,
+    UpdateSubscriptionRequest, UpdateTrialRequest, UsageBillableMetric, UsageBucket,
+    UsageGranularity, UsageMetricGroup,
 };
 pub use client::taxes::{TaxId, TaxIdRequest, TaxIdType};
 pub use client::Client;
 pub use config::{ClientBuilder, ClientConfig, ListParams};
 pub use error::{ApiError, Error};
+pub use money::{Amount, Money};
+
+/// Derives [`OrbEvent`] for a struct with named fields.
+///
+/// The event name is declared with `#[orb(event_name = "...")]` on the
+/// struct. Each field becomes a property of the same name, unless renamed
+/// with `#[orb(rename = "...")]`.
+#[cfg(feature = "derive")]
+pub use orb_billing_derive::OrbEvent;
@@ -0,0 +1,429 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use codes_iso_4217::CurrencyCode;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An exact decimal quantity, like a monetary amount or a number of units.
+///
+/// Orb represents amounts as decimal strings in some places and as JSON
+/// numbers in others. `Amount` accepts either when deserializing, and
+/// preserves the number of decimal places it was supplied with, so that,
+/// e.g., an amount of `"1.50"` is displayed as `1.50`. Amounts compare equal
+/// if their values are equal, regardless of their number of decimal places.
+///
+/// Arithmetic on amounts is exact. The arithmetic operators panic on
+/// overflow; use the `checked_*` methods to handle overflow gracefully.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(Decimal);
+
+impl Amount {
+    /// The amount zero.
+    pub const ZERO: Amount = Amount(Decimal::ZERO);
+
+    /// Constructs an amount from an integer and a number of decimal places.
+    ///
+    /// For example, `Amount::new(150, 2)` represents `1.50`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is greater than 28.
+    pub fn new(num: i64, scale: u32) -> Amount {
+        Amount(Decimal::new(num, scale))
+    }
+
+    /// Returns the underlying decimal.
+    pub fn to_decimal(self) -> Decimal {
+        self.0
+    }
+
+    /// Converts the amount to the nearest floating-point number.
+    pub fn to_f64(self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Returns the number of decimal places in the amount.
+    pub fn scale(self) -> u32 {
+        self.0.scale()
+    }
+
+    /// Reports whether the amount is zero.
+    pub fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Reports whether the amount is less than zero.
+    pub fn is_negative(self) -> bool {
+        self.0.is_sign_negative() && !self.0.is_zero()
+    }
+
+    /// Returns the absolute value of the amount.
+    pub fn abs(self) -> Amount {
+        Amount(self.0.abs())
+    }
+
+    /// Rounds the amount to `dp` decimal places, rounding halfway values
+    /// away from zero.
+    pub fn round_dp(self, dp: u32) -> Amount {
+        Amount(
+            self.0
+                .round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero),
+        )
+    }
+
+    /// Removes trailing zeros from the fractional part of the amount.
+    pub fn normalize(self) -> Amount {
+        Amount(self.0.normalize())
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub fn checked_add(self, other: Amount) -> Option<Amount> {
+        self.0.checked_add(other.0).map(Amount)
+    }
+
+    /// Subtracts two amounts, returning `None` on overflow.
+    pub fn checked_sub(self, other: Amount) -> Option<Amount> {
+        self.0.checked_sub(other.0).map(Amount)
+    }
+
+    /// Multiplies two amounts, returning `None` on overflow.
+    pub fn checked_mul(self, other: Amount) -> Option<Amount> {
+        self.0.checked_mul(other.0).map(Amount)
+    }
+
+    /// Divides two amounts, returning `None` on overflow or if `other` is
+    /// zero.
+    pub fn checked_div(self, other: Amount) -> Option<Amount> {
+        self.0.checked_div(other.0).map(Amount)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for Amount {
+    type Err = rust_decimal::Error;
+
+    fn from_str(s: &str) -> Result<Amount, Self::Err> {
+        match Decimal::from_str_exact(s) {
+            Ok(d) => Ok(Amount(d)),
+            Err(_) => Decimal::from_scientific(s).map(Amount),
+        }
+    }
+}
+
+impl From<Decimal> for Amount {
+    fn from(d: Decimal) -> Amount {
+        Amount(d)
+    }
+}
+
+impl From<Amount> for Decimal {
+    fn from(amount: Amount) -> Decimal {
+        amount.0
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Amount {
+                fn from(n: $t) -> Amount {
+                    Amount(Decimal::from(n))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl TryFrom<f64> for Amount {
+    type Error = rust_decimal::Error;
+
+    /// Converts a floating-point number to the amount with the fewest
+    /// decimal places that round trips to the same number.
+    fn try_from(n: f64) -> Result<Amount, Self::Error> {
+        if !n.is_finite() {
+            return Err(rust_decimal::Error::ConversionTo("Amount".into()));
+        }
+        // Rust formats floats using the shortest representation that
+        // round trips.
+        n.to_string().parse()
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, other: Amount) -> Amount {
+        Amount(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Amount {
+    fn add_assign(&mut self, other: Amount) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, other: Amount) -> Amount {
+        Amount(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Amount {
+    fn sub_assign(&mut self, other: Amount) {
+        self.0 -= other.0;
+    }
+}
+
+impl Mul for Amount {
+    type Output = Amount;
+
+    fn mul(self, other: Amount) -> Amount {
+        Amount(self.0 * other.0)
+    }
+}
+
+impl MulAssign for Amount {
+    fn mul_assign(&mut self, other: Amount) {
+        self.0 *= other.0;
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        Amount(-self.0)
+    }
+}
+
+impl Sum for Amount {
+    fn sum<I>(iter: I) -> Amount
+    where
+        I: Iterator<Item = Amount>,
+    {
+        iter.fold(Amount::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Amount> for Amount {
+    fn sum<I>(iter: I) -> Amount
+    where
+        I: Iterator<Item = &'a Amount>,
+    {
+        iter.copied().sum()
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Amount, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AmountVisitor;
+
+        impl<'de> Visitor<'de> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a decimal number or string")
+            }
+
+            fn visit_i64<E>(self, n: i64) -> Result<Amount, E> {
+                Ok(Amount::from(n))
+            }
+
+            fn visit_u64<E>(self, n: u64) -> Result<Amount, E> {
+                Ok(Amount::from(n))
+            }
+
+            fn visit_f64<E>(self, n: f64) -> Result<Amount, E>
+            where
+                E: de::Error,
+            {
+                Amount::try_from(n).map_err(E::custom)
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Amount, E>
+            where
+                E: de::Error,
+            {
+                s.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+/// Serializes an [`Amount`] as a JSON number rather than a string, for the
+/// fields where Orb requires a number.
+///
+/// Amounts that cannot be represented exactly as a floating-point number are
+/// rejected rather than silently rounded.
+pub(crate) mod number {
+    use rust_decimal::prelude::ToPrimitive;
+    use serde::ser::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Amount;
+
+    pub fn serialize<S>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(n) = amount.0.is_integer().then(|| amount.0.to_i64()).flatten() {
+            return serializer.serialize_i64(n);
+        }
+        let n = amount.to_f64();
+        if Amount::try_from(n).ok() != Some(*amount) {
+            return Err(S::Error::custom(format!(
+                "amount {amount} cannot be represented exactly as a JSON number"
+            )));
+        }
+        serializer.serialize_f64(n)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Amount::deserialize(deserializer)
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::Amount;
+
+        pub fn serialize<S>(amount: &Option<Amount>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match amount {
+                None => serializer.serialize_none(),
+                Some(amount) => super::serialize(amount, serializer),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Amount>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<Amount>::deserialize(deserializer)
+        }
+    }
+}
+
+/// An [`Amount`] of a particular currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    /// The amount.
+    pub amount: Amount,
+    /// The currency of the amount.
+    pub currency: CurrencyCode,
+}
+
+impl Money {
+    /// Constructs a new `Money`.
+    pub fn new(amount: Amount, currency: CurrencyCode) -> Money {
+        Money { amount, currency }
+    }
+
+    /// Constructs a new `Money` from an amount and an ISO 4217 currency code.
+    ///
+    /// Returns `None` if `currency` is not a valid currency code, as is the
+    /// case for amounts denominated in Orb credits.
+    pub fn from_code(amount: Amount, currency: &str) -> Option<Money> {
+        let currency = currency.parse().ok()?;
+        Some(Money { amount, currency })
+    }
+
+    /// Returns zero in the specified currency.
+    pub fn zero(currency: CurrencyCode) -> Money {
+        Money::new(Amount::ZERO, currency)
+    }
+
+    /// Rounds the amount to the number of decimal places used by the
+    /// currency, e.g., two decimal places for US dollars.
+    pub fn round(self) -> Money {
+        // Currencies without minor units, like the Japanese yen, have no
+        // recorded number of minor units.
+        let dp = self.currency.monetary_units().unwrap_or(0);
+        Money::new(self.amount.round_dp(dp.into()), self.currency)
+    }
+
+    /// Adds two amounts of money.
+    ///
+    /// Returns `None` if the currencies differ or on overflow.
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        let amount = self.amount.checked_add(other.amount)?;
+        Some(Money::new(amount, self.currency))
+    }
+
+    /// Subtracts two amounts of money.
+    ///
+    /// Returns `None` if the currencies differ or on overflow.
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        let amount = self.amount.checked_sub(other.amount)?;
+        Some(Money::new(amount, self.currency))
+    }
+
+    /// Multiplies the amount of money by a quantity, returning `None` on
+    /// overflow.
+    pub fn checked_mul(self, quantity: Amount) -> Option<Money> {
+        let amount = self.amount.checked_mul(quantity)?;
+        Some(Money::new(amount, self.currency))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.amount, f)?;
+        write!(f, " {}", self.currency.alpha_code())
+    }
+}
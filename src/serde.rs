@@ -28,3 +28,41 @@ impl<'de> Deserialize<'de> for Empty {
         Ok(Empty)
     }
 }
+
+/// A struct that deserializes the `data` field of a non-paginated list
+/// response.
+#[derive(Deserialize)]
+pub struct ArrayResponse<T> {
+    pub data: Vec<T>,
+}
+
+/// Serializes datetimes as RFC 3339 strings after converting them to UTC, as
+/// Orb requires supplied datetimes be in UTC.
+///
+/// For use with `#[serde(with = "...")]` on request fields.
+pub mod utc_rfc3339 {
+    use serde::Serializer;
+    use time::{OffsetDateTime, UtcOffset};
+
+    pub fn serialize<S>(date: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        time::serde::rfc3339::serialize(&date.to_offset(UtcOffset::UTC), serializer)
+    }
+
+    pub mod option {
+        use serde::Serializer;
+        use time::OffsetDateTime;
+
+        pub fn serialize<S>(date: &Option<OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match date {
+                None => serializer.serialize_none(),
+                Some(date) => super::serialize(date, serializer),
+            }
+        }
+    }
+}
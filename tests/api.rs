@@ -29,8 +29,9 @@ use std::env;
 use std::fmt;
 use std::ops::{Add, Sub};
 
-use ::time::{OffsetDateTime, Time};
+use ::time::{OffsetDateTime, Time, UtcOffset};
 use codes_iso_3166::part_1::CountryCode;
+use codes_iso_4217::CurrencyCode;
 use futures::future;
 use futures::stream::TryStreamExt;
 use once_cell::sync::Lazy;
@@ -41,13 +42,27 @@ use tokio::time::{self, Duration};
 use tracing::info;
 
 use orb_billing::{
-    AddIncrementCreditLedgerEntryRequestParams, AddVoidCreditLedgerEntryRequestParams, Address,
-    AddressRequest, AmendEventRequest, Client, ClientConfig, CostViewMode, CreateCustomerRequest,
-    CreateSubscriptionRequest, Customer, CustomerCostParams, CustomerCostPriceBlockPrice,
-    CustomerId, CustomerPaymentProviderRequest, Error, Event, EventPropertyValue,
-    EventSearchParams, IngestEventRequest, IngestionMode, InvoiceListParams, LedgerEntry,
-    LedgerEntryRequest, ListParams, PaymentProvider, SubscriptionListParams, TaxId, TaxIdRequest,
-    UpdateCustomerRequest, VoidReason,
+    AddIncrementCreditLedgerEntryRequestParams, AddPriceIntervalRequest,
+    AddVoidCreditLedgerEntryRequestParams, Address, AddressRequest, AmendEventRequest, Amount,
+    BalanceTransactionAction, BalanceTransactionType, BillableMetricStatus, BulkEventParams,
+    Catalog, CatalogChange, CatalogItem, CatalogPlan, CatalogPriceSpec, Client, ClientConfig,
+    CostViewMode, CouponDiscountRequest, CouponListParams, CreateBalanceTransactionRequest,
+    CreateBillableMetricRequest, CreateCouponRequest, CreateCreditNoteLineItemRequest,
+    CreateCreditNoteRequest, CreateCustomerRequest, CreateItemRequest, CreatePlanPriceRequest,
+    CreatePlanRequest, CreatePriceRequest, CreateSubscriptionRequest, CreditNoteListParams,
+    CreditNoteReason, Customer, CustomerCostParams, CustomerId, CustomerListParams,
+    CustomerPaymentProviderRequest, CustomerReportingConfiguration,
+    CustomerTaxConfigurationRequest, Discount, EditPriceIntervalRequest, Error,
+    EvaluatePriceRequest, Event, EventAggregate, EventAggregationKey, EventAggregator,
+    EventPropertyValue, EventSearchParams, EventVolumeParams, FixedFeeQuantityChangeOption,
+    IngestEventRequest, IngestionMode, InvoiceListParams, ItemExternalConnection,
+    ItemExternalConnectionName, ItemExternalConnectionRequest, LedgerEntry, LedgerEntryRequest,
+    ListParams, PaymentProvider, PlanListParams, Price, PriceCadence, PriceId, PriceIntervalDate,
+    PriceIntervalsRequest, PriceModelConfig, RedeemCouponRequest, SubscriptionChangeOption,
+    SubscriptionListParams, SubscriptionStatus, SubscriptionUsageParams, TaxId, TaxIdRequest,
+    TrialEndDate, UnitConfig, UpdateBillableMetricRequest, UpdateCustomerRequest,
+    UpdateFixedFeeQuantityRequest, UpdateItemRequest, UpdatePlanRequest, UpdateSubscriptionRequest,
+    UpdateTrialRequest, UsageGranularity, VoidReason,
 };
 
 /// The API key to authenticate with.
@@ -79,7 +94,7 @@ fn new_client() -> Client {
 
 async fn delete_all_test_customers(client: &Client) {
     client
-        .list_customers(&MAX_PAGE_LIST_PARAMS)
+        .list_customers(&CustomerListParams::DEFAULT.page_size(500))
         .try_filter(|customer| future::ready(customer.name.starts_with(TEST_PREFIX)))
         .try_for_each_concurrent(Some(CONCURRENCY_LIMIT), |customer| async move {
             info!(%customer.id, "deleting customer");
@@ -141,7 +156,8 @@ async fn test_customers() {
     assert_eq!(customer.email, email);
     assert_eq!(customer.external_id.as_ref(), Some(&external_id));
     assert_eq!(customer.timezone, "America/New_York");
-    assert_eq!(customer.balance, "0.00");
+    assert_eq!(customer.balance, Amount::ZERO);
+    assert_eq!(customer.balance.to_string(), "0.00");
     assert_eq!(customer.billing_address, None);
     assert_eq!(customer.shipping_address, None);
     assert_eq!(customer.tax_id, None);
@@ -166,7 +182,7 @@ async fn test_customers() {
         .create_ledger_entry(
             &customer.id,
             &LedgerEntryRequest::Increment(AddIncrementCreditLedgerEntryRequestParams {
-                amount: serde_json::Number::from(42),
+                amount: Amount::from(42),
                 description: Some("Test credit"),
                 expiry_date: None,
                 effective_date: None,
@@ -186,7 +202,7 @@ async fn test_customers() {
         .try_collect()
         .await
         .unwrap();
-    assert_eq!(balance.get(0).unwrap().balance, inc_res.ledger.amount);
+    assert_eq!(balance.first().unwrap().balance, inc_res.ledger.amount);
     let ledger_res = client
         .create_ledger_entry(
             &customer.id,
@@ -213,6 +229,46 @@ async fn test_customers() {
         .await
         .unwrap();
     assert!(balance.is_empty());
+
+    // Test adjusting the customer's balance.
+    let increment = client
+        .create_balance_transaction(
+            &customer.id,
+            &CreateBalanceTransactionRequest {
+                type_: BalanceTransactionType::Increment,
+                amount: Amount::new(1000, 2),
+                description: Some("Test adjustment"),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(increment.type_, BalanceTransactionType::Increment);
+    assert_eq!(increment.action, BalanceTransactionAction::ManualAdjustment);
+    assert_eq!(increment.description.as_deref(), Some("Test adjustment"));
+    assert_eq!(increment.invoice, None);
+    assert_eq!(increment.credit_note, None);
+    let decrement = client
+        .create_balance_transaction(
+            &customer.id,
+            &CreateBalanceTransactionRequest {
+                type_: BalanceTransactionType::Decrement,
+                amount: Amount::new(1000, 2),
+                description: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(decrement.starting_balance, increment.ending_balance);
+    let transactions: Vec<_> = client
+        .list_balance_transactions(&customer.id, &MAX_PAGE_LIST_PARAMS)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(transactions.contains(&increment));
+    assert!(transactions.contains(&decrement));
+    let customer = client.get_customer(&customer.id).await.unwrap();
+    assert_eq!(customer.balance, Amount::ZERO);
+
     // Test a second creation request with the same idempotency key does
     // *not* create a new instance
     let res = client
@@ -291,12 +347,58 @@ async fn test_customers() {
     let customer = client.get_customer(&customer.id).await.unwrap();
     assert_eq!(customer.email, "orb-testing+update-2@materialize.com");
 
+    // Test updating the customer's metadata and collection settings.
+    let metadata = BTreeMap::from([
+        ("org_id".to_string(), Some("org-1".to_string())),
+        ("region".to_string(), Some("us-east-1".to_string())),
+    ]);
+    let customer = client
+        .update_customer(
+            &customer.id,
+            &UpdateCustomerRequest {
+                metadata: Some(&metadata),
+                auto_collection: Some(false),
+                email_delivery: Some(false),
+                reporting_configuration: Some(CustomerReportingConfiguration { exempt: true }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(customer.metadata.get("org_id"), Some(&"org-1".to_string()));
+    assert_eq!(
+        customer.metadata.get("region"),
+        Some(&"us-east-1".to_string())
+    );
+    assert!(!customer.auto_collection);
+    assert!(!customer.email_delivery);
+    assert_eq!(
+        customer.reporting_configuration,
+        Some(CustomerReportingConfiguration { exempt: true })
+    );
+    let metadata = BTreeMap::from([("region".to_string(), None)]);
+    let customer = client
+        .update_customer(
+            &customer.id,
+            &UpdateCustomerRequest {
+                metadata: Some(&metadata),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(customer.metadata.get("org_id"), Some(&"org-1".to_string()));
+    assert_eq!(customer.metadata.get("region"), None);
+    assert_eq!(client.get_customer(&customer.id).await.unwrap(), customer);
+
     // Test creating a second customer, and exercise addresses and tax IDs.
     let email2 = "orb-testing+2@materialize.com";
+    let metadata2 = BTreeMap::from([("org_id".to_string(), "org-2".to_string())]);
     let customer2 = client
         .create_customer(&CreateCustomerRequest {
             name: &format!("{TEST_PREFIX}-{nonce}-2"),
             email: email2,
+            metadata: Some(&metadata2),
             shipping_address: Some(AddressRequest {
                 city: Some("New York"),
                 country: Some(CountryCode::US),
@@ -315,10 +417,19 @@ async fn test_customers() {
                 value: "12-3456789",
                 country: CountryCode::US,
             }),
+            tax_configuration: Some(CustomerTaxConfigurationRequest::Avalara {
+                tax_exempt: true,
+                tax_exemption_code: Some("E"),
+            }),
+            reporting_configuration: Some(CustomerReportingConfiguration { exempt: false }),
             ..Default::default()
         })
         .await
         .unwrap();
+    assert_eq!(
+        customer2.reporting_configuration,
+        Some(CustomerReportingConfiguration { exempt: false })
+    );
     assert_eq!(
         customer2.shipping_address,
         Some(Address {
@@ -349,17 +460,60 @@ async fn test_customers() {
             country: CountryCode::US,
         })
     );
+    assert_eq!(customer2.metadata, metadata2);
 
     // List customers, and ensure we see both customers that we created.
     // Do so with a page size of one to exercise the pagination logic.
     let customer_ids: HashSet<_> = client
-        .list_customers(&ListParams::default().page_size(1))
+        .list_customers(&CustomerListParams::default().page_size(1))
         .map_ok(|customer| customer.id)
         .try_collect()
         .await
         .unwrap();
     assert!(customer_ids.contains(&customer.id));
     assert!(customer_ids.contains(&customer2.id));
+
+    // Test filtering customers by creation time.
+    let customer_ids: HashSet<_> = client
+        .list_customers(&CustomerListParams::DEFAULT.created_at_gt(customer.created_at))
+        .map_ok(|customer| customer.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(!customer_ids.contains(&customer.id));
+    assert!(customer_ids.contains(&customer2.id));
+    let customer_ids: HashSet<_> = client
+        .list_customers(
+            &CustomerListParams::DEFAULT
+                .created_at_gte(customer.created_at)
+                .created_at_lt(customer2.created_at),
+        )
+        .map_ok(|customer| customer.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(customer_ids.contains(&customer.id));
+    assert!(!customer_ids.contains(&customer2.id));
+
+    // Test filtering and finding customers by email.
+    let customers: Vec<_> = client
+        .list_customers(&CustomerListParams::DEFAULT.email(&customer2.email.to_uppercase()))
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(customers.iter().all(|c| c.email == customer2.email));
+    assert!(customers.iter().any(|c| c.id == customer2.id));
+    let found = client
+        .find_customer_by_email(&customer2.email)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.email, customer2.email);
+    let found = client
+        .find_customer_by_email("$NOEXIST$@materialize.com")
+        .await
+        .unwrap();
+    assert_eq!(found, None);
 }
 
 #[test(tokio::test)]
@@ -466,6 +620,24 @@ async fn test_events() {
         .unwrap();
     assert!(events.debug.is_none());
 
+    // Test that events with properties that Orb does not accept are rejected
+    // before being sent.
+    let mut invalid_properties = BTreeMap::new();
+    invalid_properties.insert("test".into(), EventPropertyValue::Null);
+    let res = client
+        .ingest_events(
+            IngestionMode::Debug,
+            &[IngestEventRequest {
+                customer_id: CustomerId::Orb(&customer.id),
+                idempotency_key: &ids[0],
+                event_name: "test",
+                properties: &invalid_properties,
+                timestamp: timestamps[0],
+            }],
+        )
+        .await;
+    assert!(matches!(res, Err(Error::InvalidRequest { .. })));
+
     // Extremely sketchy sleep seems to be required for search results to
     // reflect the ingestion
     time::sleep(Duration::from_secs(20)).await;
@@ -490,6 +662,7 @@ async fn test_events() {
                 event_name: "test".into(),
                 properties: BTreeMap::new(),
                 timestamp: timestamps[0],
+                deprecated: false,
             },
             Event {
                 id: ids[1].clone(),
@@ -498,6 +671,7 @@ async fn test_events() {
                 event_name: "test".into(),
                 properties: BTreeMap::new(),
                 timestamp: timestamps[1],
+                deprecated: false,
             },
             Event {
                 id: ids[2].clone(),
@@ -506,10 +680,71 @@ async fn test_events() {
                 event_name: "test".into(),
                 properties: BTreeMap::new(),
                 timestamp: timestamps[2],
+                deprecated: false,
             },
         ]
     );
 
+    // Test filtering the search by customer and event name.
+    let events: Vec<_> = client
+        .search_events(
+            &EventSearchParams::default()
+                .customer_id(CustomerId::Orb(&customer.id))
+                .event_names(&["test"])
+                .timeframe_start(timestamps[0])
+                .timeframe_end(timeframe_end),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 3);
+    let events: Vec<_> = client
+        .search_events(
+            &EventSearchParams::default()
+                .customer_id(CustomerId::Orb(&customer.id))
+                .event_names(&["not-test"])
+                .timeframe_start(timestamps[0])
+                .timeframe_end(timeframe_end),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(events.is_empty());
+
+    // Test aggregating the ingested events.
+    let aggregator = client
+        .aggregate_events(
+            &EventSearchParams::default()
+                .event_ids(&[&ids[0], &ids[1], &ids[2]])
+                .timeframe_end(timeframe_end),
+            EventAggregator::new(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        aggregator.into_groups(),
+        BTreeMap::from([(
+            EventAggregationKey {
+                event_name: "test".into(),
+                customer_id: customer.id.clone(),
+                group: None,
+            },
+            EventAggregate {
+                count: 3,
+                sum: Amount::ZERO,
+                max: None,
+            }
+        )])
+    );
+
+    // Test that the event volume reflects the ingested events.
+    let volume: Vec<_> = client
+        .get_event_volume(&EventVolumeParams::new(timestamps[0]).timeframe_end(timeframe_end))
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(volume.iter().map(|bucket| bucket.count).sum::<u64>() >= 3);
+
     // Test amending an event.
     let mut properties = BTreeMap::new();
     properties.insert("test".into(), EventPropertyValue::Bool(false));
@@ -541,7 +776,7 @@ async fn test_events() {
             .try_collect()
             .await
             .unwrap();
-        if events.get(0).map(|e| e.event_name.clone()) != Some("new test".into()) {
+        if events.first().map(|e| e.event_name.clone()) != Some("new test".into()) {
             info!("  events list not updated after {iteration} attempts.");
             if iteration < MAX_LIST_RETRIES {
                 continue;
@@ -551,6 +786,143 @@ async fn test_events() {
         // Exit the loop
         break;
     }
+
+    // Test filtering the search by property, which matches only the amended
+    // event.
+    let events: Vec<_> = client
+        .search_events(
+            &EventSearchParams::default()
+                .event_ids(&[&ids[0], &ids[1], &ids[2]])
+                .properties(&[("test", EventPropertyValue::Bool(false))])
+                .timeframe_end(timeframe_end),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(events.iter().map(|e| &e.id).collect::<Vec<_>>(), [&ids[0]]);
+    let events: Vec<_> = client
+        .search_events(
+            &EventSearchParams::default()
+                .event_ids(&[&ids[0], &ids[1], &ids[2]])
+                .properties(&[("test", EventPropertyValue::String("false".into()))])
+                .timeframe_end(timeframe_end),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(events.is_empty());
+
+    // Test bulk deprecation, first as a dry run.
+    let event_ids = [&*ids[0], &*ids[1], &*ids[2]];
+    let search = EventSearchParams::default()
+        .event_ids(&event_ids)
+        .timeframe_end(timeframe_end);
+    let report = client
+        .bulk_deprecate_events(
+            &BulkEventParams::default()
+                .search(search.clone())
+                .dry_run(true),
+            |event| event.id != ids[2],
+        )
+        .await
+        .unwrap();
+    assert_eq!(report.searched, 3);
+    assert_eq!(report.skipped, 1);
+    let mut succeeded = report.succeeded.clone();
+    succeeded.sort();
+    assert_eq!(succeeded, vec![ids[0].clone(), ids[1].clone()]);
+    let report = client
+        .bulk_deprecate_events(&BulkEventParams::default().search(search), |event| {
+            event.id != ids[2]
+        })
+        .await
+        .unwrap();
+    assert!(report.failed.is_empty(), "{:?}", report.failed);
+    assert_eq!(report.succeeded.len(), 2);
+
+    // Test that deprecated events can be excluded from the search, and that
+    // any deprecated events that Orb returns are included by default.
+    let events: Vec<_> = client
+        .search_events(
+            &EventSearchParams::default()
+                .event_ids(&event_ids)
+                .timeframe_end(timeframe_end)
+                .include_deprecated(false),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(events.iter().map(|e| &e.id).collect::<Vec<_>>(), [&ids[2]]);
+    let events: Vec<_> = client
+        .search_events(
+            &EventSearchParams::default()
+                .event_ids(&event_ids)
+                .timeframe_end(timeframe_end),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(events.iter().any(|e| e.id == ids[2] && !e.deprecated));
+    assert!(events
+        .iter()
+        .filter(|e| e.id != ids[2])
+        .all(|e| e.deprecated));
+
+    // Test bulk amendment, first by moving the remaining event to a customer
+    // with an external ID and then by moving it back by Orb ID.
+    let external_id = format!("{TEST_PREFIX}-{}", customer_idx + 1);
+    let other_customer = client
+        .create_customer(&CreateCustomerRequest {
+            name: &external_id,
+            email: "orb-testing-bulk@materialize.com",
+            external_id: Some(&external_id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let event_ids = [&*ids[2]];
+    let search = EventSearchParams::default()
+        .event_ids(&event_ids)
+        .timeframe_end(timeframe_end)
+        .include_deprecated(false);
+    for (amended_customer_id, amended_external_customer_id) in [
+        (&other_customer.id, Some(&external_id)),
+        (&customer.id, None),
+    ] {
+        let report = client
+            .bulk_amend_events(
+                &BulkEventParams::default().search(search.clone()),
+                |event| {
+                    match amended_external_customer_id {
+                        Some(id) => event.external_customer_id = Some(id.clone()),
+                        None => event.customer_id = amended_customer_id.clone(),
+                    }
+                    event.event_name = "bulk test".into();
+                    true
+                },
+            )
+            .await
+            .unwrap();
+        assert!(report.failed.is_empty(), "{:?}", report.failed);
+        assert_eq!(report.succeeded, vec![ids[2].clone()]);
+
+        // As above, Orb takes its time registering the amendment.
+        for iteration in 1..=MAX_LIST_RETRIES {
+            time::sleep(Duration::from_secs(60)).await;
+
+            let events: Vec<_> = client.search_events(&search).try_collect().await.unwrap();
+            if events.first().map(|e| &e.customer_id) != Some(amended_customer_id) {
+                info!("  events list not updated after {iteration} attempts.");
+                if iteration < MAX_LIST_RETRIES {
+                    continue;
+                }
+            }
+            assert_eq!(events.len(), 1);
+            assert_eq!(&events[0].customer_id, amended_customer_id);
+            assert_eq!(events[0].event_name, "bulk test");
+            break;
+        }
+    }
 }
 
 #[test(tokio::test)]
@@ -558,17 +930,463 @@ async fn test_plans() {
     let client = new_client();
 
     let plans: Vec<_> = client
-        .list_plans(&MAX_PAGE_LIST_PARAMS)
+        .list_plans(&PlanListParams::DEFAULT.page_size(500))
         .try_collect()
         .await
         .unwrap();
     println!("plans = {:#?}", plans);
 
-    // TODO: validate list results.
-    // TODO: test get_plan.
-    // TODO: test get_plan_by_external_id.
+    // Test fetching the plans used by the subscription tests.
+    for external_id in ["test", "test-complex"] {
+        let plan = client.get_plan_by_external_id(external_id).await.unwrap();
+        assert_eq!(plan.external_id.as_deref(), Some(external_id));
+        assert!(!plan.prices.is_empty());
+        assert!(plans.contains(&plan));
+        let fetched = client.get_plan(&plan.id).await.unwrap();
+        assert_eq!(fetched, plan);
+    }
+
+    // Test creating a plan, updating its metadata and fetching it back. The
+    // plan prices the same item as the "test" plan.
+    let nonce = rand::thread_rng().gen::<u32>();
+    let test_plan = client.get_plan_by_external_id("test").await.unwrap();
+    let item_id = &test_plan.prices[0].base().unwrap().item.id;
+    let external_id = format!("{TEST_PREFIX}-plan-{nonce}");
+    let plan = client
+        .create_plan(&CreatePlanRequest {
+            name: &external_id,
+            currency: CurrencyCode::USD,
+            prices: vec![CreatePlanPriceRequest {
+                name: "Test fee",
+                item_id,
+                cadence: PriceCadence::Monthly,
+                model: PriceModelConfig::Unit {
+                    unit_config: UnitConfig {
+                        unit_amount: Amount::new(150, 2),
+                    },
+                },
+                external_id: None,
+                billable_metric_id: None,
+                fixed_price_quantity: Some(Amount::from(1)),
+                invoice_grouping_key: None,
+            }],
+            external_id: Some(&external_id),
+            metadata: Some(&BTreeMap::from([
+                ("purpose".into(), "test".into()),
+                ("nonce".into(), nonce.to_string()),
+            ])),
+            net_terms: Some(7),
+            default_invoice_memo: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(plan.external_id.as_deref(), Some(&*external_id));
+    assert_eq!(plan.net_terms, Some(7));
+    assert_eq!(plan.prices.len(), 1);
+    assert_eq!(plan.metadata.get("purpose"), Some(&"test".to_string()));
+
+    let metadata = BTreeMap::from([
+        ("purpose".to_string(), Some("updated".to_string())),
+        ("nonce".to_string(), None),
+    ]);
+    let updated = client
+        .update_plan(
+            &plan.id,
+            &UpdatePlanRequest {
+                metadata: Some(&metadata),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        updated.metadata,
+        BTreeMap::from([("purpose".to_string(), "updated".to_string())])
+    );
+    let fetched = client.get_plan(&plan.id).await.unwrap();
+    assert_eq!(fetched.metadata, updated.metadata);
+    assert_eq!(fetched.external_id.as_deref(), Some(&*external_id));
+
     // TODO: test get_plan w/nested plans?
-    // Testing the above will be hard as there is no API to create plans.
+}
+
+#[test(tokio::test)]
+async fn test_catalog() {
+    let client = new_client();
+
+    // An empty catalog requires no changes unless pruning.
+    let changeset = client.diff_catalog(&Catalog::default()).await.unwrap();
+    assert!(changeset.is_empty());
+    client.apply_catalog_changeset(&changeset).await.unwrap();
+
+    // Pruning archives the plans used by the subscription tests, which can
+    // only be done manually.
+    let catalog = Catalog {
+        prune: true,
+        ..Default::default()
+    };
+    let changeset = client.diff_catalog(&catalog).await.unwrap();
+    println!("changeset:\n{changeset}");
+    assert!(!changeset.has_conflicts());
+    for external_id in ["test", "test-complex"] {
+        assert!(changeset.changes.iter().any(|change| matches!(
+            change,
+            CatalogChange::ArchivePlan { external_id: id, .. } if id == external_id
+        )));
+    }
+    let manual = client.apply_catalog_changeset(&changeset).await.unwrap();
+    assert_eq!(manual.len(), changeset.changes.len());
+
+    // Test that applying a catalog brings the account in line with it. Items
+    // cannot be deleted, so reuse the test item across runs, but create a
+    // new plan each run.
+    let nonce = rand::thread_rng().gen::<u32>();
+    let item_name = format!("{TEST_PREFIX}-item");
+    let mut catalog = Catalog {
+        items: vec![CatalogItem {
+            name: item_name.clone(),
+        }],
+        plans: vec![CatalogPlan {
+            external_id: format!("{TEST_PREFIX}-catalog-{nonce}"),
+            name: format!("{TEST_PREFIX}-catalog-{nonce}"),
+            currency: CurrencyCode::USD,
+            prices: vec![CatalogPriceSpec {
+                name: "Test fee".into(),
+                item: item_name,
+                cadence: PriceCadence::Monthly,
+                model: PriceModelConfig::Unit {
+                    unit_config: UnitConfig {
+                        unit_amount: Amount::new(150, 2),
+                    },
+                },
+                billable_metric: None,
+                fixed_price_quantity: Some(Amount::from(1)),
+            }],
+            metadata: BTreeMap::from([("tier".into(), "1".into())]),
+            net_terms: None,
+            default_invoice_memo: None,
+        }],
+        ..Default::default()
+    };
+    let changeset = client.diff_catalog(&catalog).await.unwrap();
+    println!("changeset:\n{changeset}");
+    assert!(changeset
+        .changes
+        .iter()
+        .any(|change| matches!(change, CatalogChange::CreatePlan(_))));
+    let manual = client.apply_catalog_changeset(&changeset).await.unwrap();
+    assert!(manual.is_empty());
+    let changeset = client.diff_catalog(&catalog).await.unwrap();
+    assert!(changeset.is_empty(), "{changeset}");
+
+    // Test that metadata changes are applied in place.
+    catalog.plans[0].metadata = BTreeMap::from([("tier".into(), "2".into())]);
+    let changeset = client.diff_catalog(&catalog).await.unwrap();
+    assert_eq!(changeset.changes.len(), 1);
+    assert!(matches!(
+        changeset.changes[0],
+        CatalogChange::UpdatePlanMetadata { .. }
+    ));
+    client.apply_catalog_changeset(&changeset).await.unwrap();
+    let changeset = client.diff_catalog(&catalog).await.unwrap();
+    assert!(changeset.is_empty(), "{changeset}");
+
+    // Test that other changes are reported as conflicts, which prevent the
+    // changeset from being applied.
+    catalog.plans[0].name = format!("{TEST_PREFIX}-catalog-{nonce}-renamed");
+    let changeset = client.diff_catalog(&catalog).await.unwrap();
+    assert!(changeset.has_conflicts());
+    let res = client.apply_catalog_changeset(&changeset).await;
+    assert!(matches!(res, Err(Error::InvalidRequest { .. })));
+}
+
+#[test(tokio::test)]
+async fn test_prices() {
+    let client = new_client();
+
+    let prices: Vec<_> = client
+        .list_prices(&MAX_PAGE_LIST_PARAMS)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(!prices.is_empty());
+
+    // Test fetching a price by ID and by external ID.
+    for price in prices.iter().take(5) {
+        let id = price.id().unwrap();
+        let fetched = client.get_price(id).await.unwrap();
+        assert_eq!(&fetched, price);
+        if let Some(external_id) = price.external_id() {
+            let fetched = client.get_price_by_external_id(external_id).await.unwrap();
+            assert_eq!(&fetched, price);
+        }
+    }
+
+    let res = client.get_price("$NOEXIST$").await;
+    assert_error_with_status_code(res, StatusCode::NOT_FOUND);
+}
+
+#[test(tokio::test)]
+async fn test_items_and_metrics() {
+    let client = new_client();
+    let nonce = rand::thread_rng().gen::<u32>().to_string();
+
+    // Items and metrics cannot be deleted, so reuse the test item and metric
+    // across runs.
+    let item_name = format!("{TEST_PREFIX}-item");
+    let items: Vec<_> = client
+        .list_items(&MAX_PAGE_LIST_PARAMS)
+        .try_collect()
+        .await
+        .unwrap();
+    let item = match items.into_iter().find(|item| item.name == item_name) {
+        Some(item) => item,
+        None => client
+            .create_item(&CreateItemRequest { name: &item_name })
+            .await
+            .unwrap(),
+    };
+    assert_eq!(client.get_item(&item.id).await.unwrap(), item);
+
+    // Test updating an item's metadata and external connections.
+    let metadata = BTreeMap::from([("nonce".into(), Some(nonce.clone()))]);
+    let item = client
+        .update_item(
+            &item.id,
+            &UpdateItemRequest {
+                external_connections: Some(&[ItemExternalConnectionRequest {
+                    name: ItemExternalConnectionName::Stripe,
+                    external_entity_id: "prod_fake",
+                }]),
+                metadata: Some(&metadata),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(item.name, item_name);
+    assert_eq!(item.metadata.get("nonce"), Some(&nonce));
+    assert_eq!(
+        item.external_connections,
+        [ItemExternalConnection {
+            name: ItemExternalConnectionName::Stripe,
+            external_entity_id: "prod_fake".into(),
+        }]
+    );
+
+    let metric_name = format!("{TEST_PREFIX}-metric");
+    let metrics: Vec<_> = client
+        .list_billable_metrics(&MAX_PAGE_LIST_PARAMS)
+        .try_collect()
+        .await
+        .unwrap();
+    let metric = match metrics
+        .into_iter()
+        .find(|metric| metric.name == metric_name)
+    {
+        Some(metric) => metric,
+        None => client
+            .create_billable_metric(&CreateBillableMetricRequest {
+                name: &metric_name,
+                description: Some("Counts test events"),
+                item_id: &item.id,
+                sql: "SELECT count(*) FROM events WHERE event_name = 'test'",
+                metadata: None,
+            })
+            .await
+            .unwrap(),
+    };
+    assert_eq!(metric.item.id, item.id);
+    assert_eq!(metric.status, BillableMetricStatus::Active);
+
+    // Test updating a metric's metadata.
+    let metric = client
+        .update_billable_metric(
+            &metric.id,
+            &UpdateBillableMetricRequest {
+                metadata: Some(&metadata),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(metric.metadata.get("nonce"), Some(&nonce));
+    assert_eq!(
+        client.get_billable_metric(&metric.id).await.unwrap(),
+        metric
+    );
+
+    // Test creating a usage price for the metric.
+    let external_id = format!("{TEST_PREFIX}-price-{nonce}");
+    let price = client
+        .create_price(&CreatePriceRequest {
+            name: "Test usage",
+            item_id: &item.id,
+            cadence: PriceCadence::Monthly,
+            currency: CurrencyCode::USD,
+            model: PriceModelConfig::Unit {
+                unit_config: UnitConfig {
+                    unit_amount: Amount::new(150, 2),
+                },
+            },
+            external_id: Some(&external_id),
+            billable_metric_id: Some(&metric.id),
+            fixed_price_quantity: None,
+            invoice_grouping_key: None,
+            metadata: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(price.external_id(), Some(&*external_id));
+    let base = price.base().unwrap();
+    assert_eq!(base.name.as_deref(), Some("Test usage"));
+    assert_eq!(base.currency.as_deref(), Some("USD"));
+    assert_eq!(
+        base.billable_metric.as_ref().map(|m| &m.id),
+        Some(&metric.id)
+    );
+    assert_eq!(
+        client.get_price_by_external_id(&external_id).await.unwrap(),
+        price
+    );
+
+    // Test evaluating the price for a customer without usage, over a
+    // timeframe specified in a non-UTC offset.
+    delete_all_test_customers(&client).await;
+    let customer = create_test_customer(&client, 0).await;
+    let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+    let timeframe_end = OffsetDateTime::now_utc()
+        .to_offset(offset)
+        .replace_time(Time::MIDNIGHT);
+    let groups = client
+        .evaluate_price(
+            price.id().unwrap(),
+            &EvaluatePriceRequest {
+                customer_id: CustomerId::Orb(&customer.id),
+                timeframe_start: timeframe_end - Duration::from_secs(60 * 60 * 24),
+                timeframe_end,
+                filter: None,
+                grouping_keys: &[],
+            },
+        )
+        .await
+        .unwrap();
+    assert!(groups.iter().all(|group| group.amount == Amount::ZERO));
+
+    let res = client.get_item("$NOEXIST$").await;
+    assert_error_with_status_code(res, StatusCode::NOT_FOUND);
+    let res = client.get_billable_metric("$NOEXIST$").await;
+    assert_error_with_status_code(res, StatusCode::NOT_FOUND);
+}
+
+#[test(tokio::test)]
+async fn test_coupons() {
+    let client = new_client();
+    delete_all_test_customers(&client).await;
+
+    // Test creating and retrieving a coupon.
+    let nonce = rand::thread_rng().gen::<u32>();
+    let redemption_code = format!("{TEST_PREFIX}-{nonce}");
+    let coupon = client
+        .create_coupon(&CreateCouponRequest {
+            redemption_code: &redemption_code,
+            discount: CouponDiscountRequest::Percentage {
+                percentage_discount: Amount::new(25, 2),
+            },
+            duration_in_months: Some(3),
+            max_redemptions: Some(1),
+        })
+        .await
+        .unwrap();
+    assert_eq!(coupon.redemption_code, redemption_code);
+    assert!(matches!(coupon.discount, Discount::Percentage(_)));
+    assert_eq!(coupon.times_redeemed, 0);
+    assert_eq!(coupon.duration_in_months, Some(3));
+    assert_eq!(coupon.max_redemptions, Some(1));
+    assert_eq!(coupon.archived_at, None);
+    assert_eq!(client.get_coupon(&coupon.id).await.unwrap(), coupon);
+    let coupons: Vec<_> = client
+        .list_coupons(&CouponListParams::DEFAULT.redemption_code(&redemption_code))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(coupons, std::slice::from_ref(&coupon));
+
+    // Test redeeming the coupon.
+    let customer = create_test_customer(&client, 0).await;
+    let subscription = client
+        .create_subscription(&CreateSubscriptionRequest {
+            customer_id: CustomerId::Orb(&customer.id),
+            plan_id: orb_billing::PlanId::External("test"),
+            coupon_redemption_code: Some(&redemption_code),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        subscription.redeemed_coupon.as_ref().map(|c| &c.coupon_id),
+        Some(&coupon.id)
+    );
+    let subscriptions: Vec<_> = client
+        .list_coupon_subscriptions(&coupon.id, &MAX_PAGE_LIST_PARAMS)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        subscriptions.iter().map(|s| &s.id).collect::<Vec<_>>(),
+        [&subscription.id]
+    );
+
+    // Test redeeming another coupon on the existing subscription.
+    let other_coupon = client
+        .create_coupon(&CreateCouponRequest {
+            redemption_code: &format!("{redemption_code}-other"),
+            discount: CouponDiscountRequest::Amount {
+                amount_discount: Amount::from(5),
+            },
+            duration_in_months: None,
+            max_redemptions: None,
+        })
+        .await
+        .unwrap();
+    let subscription = client
+        .redeem_coupon(
+            &subscription.id,
+            &RedeemCouponRequest {
+                coupon_id: &other_coupon.id,
+                change_option: SubscriptionChangeOption::Immediate,
+                change_date: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        subscription.redeemed_coupon.as_ref().map(|c| &c.coupon_id),
+        Some(&other_coupon.id)
+    );
+    client.archive_coupon(&other_coupon.id).await.unwrap();
+
+    // Test archiving the coupon.
+    let coupon = client.archive_coupon(&coupon.id).await.unwrap();
+    assert!(coupon.archived_at.is_some());
+    let coupons: Vec<_> = client
+        .list_coupons(&CouponListParams::DEFAULT.redemption_code(&redemption_code))
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(coupons.is_empty());
+    let coupons: Vec<_> = client
+        .list_coupons(
+            &CouponListParams::DEFAULT
+                .redemption_code(&redemption_code)
+                .show_archived(true),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(coupons, [coupon]);
+
+    let res = client.get_coupon("$NOEXIST$").await;
+    assert_error_with_status_code(res, StatusCode::NOT_FOUND);
 }
 
 #[test(tokio::test)]
@@ -630,6 +1448,53 @@ async fn test_subscriptions() {
         subscriptions.push(subscription);
     }
 
+    // Test fetching subscription usage, which is empty as no events have
+    // been ingested for the test customers.
+    let usage: Vec<_> = client
+        .get_subscription_usage(
+            &subscriptions[0].id,
+            &SubscriptionUsageParams::DEFAULT
+                .granularity(UsageGranularity::Day)
+                .view_mode(CostViewMode::Cumulative),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    for series in &usage {
+        assert_eq!(series.metric_group, None);
+        assert_eq!(series.view_mode, CostViewMode::Cumulative);
+        assert!(series.usage.iter().all(|bucket| bucket.quantity.is_zero()));
+    }
+    if let Some(series) = usage.first() {
+        let grouped: Vec<_> = client
+            .get_subscription_usage(
+                &subscriptions[0].id,
+                &SubscriptionUsageParams::DEFAULT
+                    .billable_metric_id(&series.billable_metric.id)
+                    .group_by("region"),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(grouped.iter().all(|series| series.metric_group.is_some()));
+    }
+
+    // Test fetching the subscription schedule, which contains only the
+    // initial plan.
+    let schedule: Vec<_> = client
+        .get_subscription_schedule(&subscriptions[0].id, &MAX_PAGE_LIST_PARAMS)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(schedule.len(), 1);
+    assert_eq!(
+        schedule[0].plan.id.as_ref(),
+        Some(&subscriptions[0].plan.id)
+    );
+    assert_eq!(schedule[0].plan.external_id.as_deref(), Some("test"));
+    assert_eq!(schedule[0].start_date, subscriptions[0].start_date);
+    assert_eq!(schedule[0].end_date, None);
+
     // Test that listing subscriptions returns all subscriptions.
     let first_subscription = subscriptions[0].created_at;
     let mut fetched_subscriptions: Vec<_> = client
@@ -661,6 +1526,166 @@ async fn test_subscriptions() {
         .await
         .unwrap();
     assert_eq!(fetched_subscriptions, &[subscriptions.remove(0)]);
+
+    // Test that the list can be filtered by status, plan and creation time.
+    let fetched_subscriptions: Vec<_> = client
+        .list_subscriptions(
+            &SubscriptionListParams::default()
+                .status(SubscriptionStatus::Active)
+                .plan_id(orb_billing::PlanId::External("test"))
+                .created_at_gt(first_subscription),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    for subscription in &subscriptions {
+        assert!(fetched_subscriptions.contains(subscription));
+    }
+    assert!(fetched_subscriptions.iter().all(|sub| {
+        sub.status == Some(SubscriptionStatus::Active)
+            && sub.plan.external_id.as_deref() == Some("test")
+            && sub.created_at > first_subscription
+    }));
+
+    // Test updating a subscription.
+    let metadata = BTreeMap::from([("purpose".to_string(), Some("test".to_string()))]);
+    let updated = client
+        .update_subscription(
+            &subscriptions[0].id,
+            &UpdateSubscriptionRequest {
+                net_terms: Some(7),
+                auto_collection: Some(false),
+                default_invoice_memo: Some("Thank you"),
+                metadata: Some(&metadata),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.net_terms, 7);
+    assert!(!updated.auto_collection);
+    assert_eq!(updated.default_invoice_memo, "Thank you");
+    assert_eq!(updated.metadata.get("purpose"), Some(&"test".to_string()));
+    let fetched_subscription = client.get_subscription(&updated.id).await.unwrap();
+    assert_eq!(fetched_subscription.metadata, updated.metadata);
+
+    // Test adding a price interval and then ending it.
+    let subscription = &subscriptions[0];
+    let price_id = subscription.plan.prices[0].id().unwrap();
+    let updated = client
+        .update_price_intervals(
+            &subscription.id,
+            &PriceIntervalsRequest {
+                add: &[AddPriceIntervalRequest {
+                    price_id: PriceId::Orb(price_id),
+                    start_date: PriceIntervalDate::StartOfTerm,
+                    end_date: None,
+                    fixed_fee_quantity_transitions: None,
+                }],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let added = updated
+        .price_intervals
+        .iter()
+        .find(|interval| interval.price.id() == Some(price_id) && interval.end_date.is_none())
+        .unwrap();
+    let updated = client
+        .update_price_intervals(
+            &subscription.id,
+            &PriceIntervalsRequest {
+                edit: &[EditPriceIntervalRequest {
+                    price_interval_id: &added.id,
+                    end_date: Some(PriceIntervalDate::EndOfTerm),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let ended = updated
+        .price_intervals
+        .iter()
+        .find(|interval| interval.id == added.id)
+        .unwrap();
+    assert!(ended.end_date.is_some());
+
+    // Test updating the quantity of a fixed fee, if the plan has one.
+    if let Some(fixed_fee) = subscription.fixed_fee_quantity_schedule.first() {
+        let updated = client
+            .update_fixed_fee_quantity(
+                &subscription.id,
+                &UpdateFixedFeeQuantityRequest {
+                    price_id: &fixed_fee.price_id,
+                    quantity: fixed_fee.quantity + Amount::from(1),
+                    change_option: Some(FixedFeeQuantityChangeOption::Immediate),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let latest = updated
+            .fixed_fee_quantity_schedule
+            .iter()
+            .filter(|fee| fee.price_id == fixed_fee.price_id)
+            .max_by_key(|fee| fee.start_date)
+            .unwrap();
+        assert_eq!(latest.quantity, fixed_fee.quantity + Amount::from(1));
+    }
+
+    // Test changing the end of a subscription's trial.
+    let customer = create_test_customer(&client, 3).await;
+    let subscription = client
+        .create_subscription(&CreateSubscriptionRequest {
+            customer_id: CustomerId::Orb(&customer.id),
+            plan_id: orb_billing::PlanId::External("test"),
+            trial_duration_days: Some(30),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let trial_end_date = subscription.trial_info.end_date.unwrap();
+    assert!(trial_end_date > subscription.start_date);
+    let trial_end_date = trial_end_date - Duration::from_secs(60 * 60 * 24 * 7);
+    let updated = client
+        .update_trial(
+            &subscription.id,
+            &UpdateTrialRequest {
+                trial_end_date: TrialEndDate::Date(trial_end_date),
+                shift: Some(false),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.trial_info.end_date, Some(trial_end_date));
+    let fetched_subscription = client.get_subscription(&subscription.id).await.unwrap();
+    assert_eq!(
+        fetched_subscription.trial_info.end_date,
+        Some(trial_end_date)
+    );
+
+    // Test advancing a subscription to the next phase of its plan. Like the
+    // "test" plan, the "test-phased" plan, which has two phases, must exist
+    // in the Orb account.
+    let customer = create_test_customer(&client, 4).await;
+    let subscription = client
+        .create_subscription(&CreateSubscriptionRequest {
+            customer_id: CustomerId::Orb(&customer.id),
+            plan_id: orb_billing::PlanId::External("test-phased"),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(subscription.active_plan_phase_order, Some(1));
+    let updated = client.trigger_phase(&subscription.id, None).await.unwrap();
+    assert_eq!(updated.active_plan_phase_order, Some(2));
+    assert_eq!(
+        updated.active_plan_phase().map(|phase| phase.order),
+        Some(2)
+    );
 }
 
 #[test(tokio::test)]
@@ -678,6 +1703,52 @@ async fn test_invoices() {
     // TODO: test get_invoice.
 }
 
+#[test(tokio::test)]
+async fn test_credit_notes() {
+    let client = new_client();
+
+    let credit_notes: Vec<_> = client
+        .list_credit_notes(&CreditNoteListParams::DEFAULT.page_size(500))
+        .try_collect()
+        .await
+        .unwrap();
+    println!("credit notes = {:#?}", credit_notes);
+
+    // Test fetching credit notes by ID and filtering them by customer.
+    for credit_note in credit_notes.iter().take(5) {
+        let fetched = client.get_credit_note(&credit_note.id).await.unwrap();
+        assert_eq!(&fetched, credit_note);
+        let customer_credit_notes: Vec<_> = client
+            .list_credit_notes(
+                &CreditNoteListParams::DEFAULT
+                    .customer_id(CustomerId::Orb(&credit_note.customer.id)),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(customer_credit_notes.contains(credit_note));
+        assert!(customer_credit_notes
+            .iter()
+            .all(|c| c.customer.id == credit_note.customer.id));
+    }
+
+    // Test that crediting a nonexistent line item fails.
+    let res = client
+        .create_credit_note(&CreateCreditNoteRequest {
+            line_items: &[CreateCreditNoteLineItemRequest {
+                invoice_line_item_id: "$NOEXIST$",
+                amount: Amount::new(100, 2),
+            }],
+            reason: Some(CreditNoteReason::Duplicate),
+            memo: None,
+        })
+        .await;
+    assert!(res.is_err());
+
+    let res = client.get_credit_note("$NOEXIST$").await;
+    assert_error_with_status_code(res, StatusCode::NOT_FOUND);
+}
+
 #[test(tokio::test)]
 async fn test_customer_costs() {
     let client = new_client();
@@ -723,18 +1794,21 @@ async fn test_customer_costs() {
         .per_price_costs
         .iter()
         .filter_map(|block| match &block.price {
-            CustomerCostPriceBlockPrice::Matrix(matrix_price) => {
+            Price::Matrix(matrix_price) => {
                 Some((matrix_price, block.price_groups.clone().unwrap()))
             }
             _ => None,
         })
         .next()
         .unwrap();
-    assert_eq!(matrix_price.matrix_config.default_unit_amount, "1.00");
+    assert_eq!(
+        matrix_price.matrix_config.default_unit_amount,
+        Amount::from(1)
+    );
     assert_eq!(matrix_price.matrix_config.dimensions.len(), 2);
     assert_eq!(
         matrix_price.matrix_config.matrix_values[0].unit_amount,
-        "2.00"
+        Amount::from(2)
     );
     assert_eq!(
         vec![
@@ -743,6 +1817,21 @@ async fn test_customer_costs() {
         ],
         matrix_price.matrix_config.matrix_values[0].dimension_values
     );
+
+    // The customer has a single subscription, so the subscription's costs
+    // match the customer's costs.
+    let subscription_costs = client
+        .get_subscription_costs(
+            &subscription.id,
+            &CustomerCostParams::default()
+                .view_mode(CostViewMode::Periodic)
+                .timeframe_start(&then)
+                .timeframe_end(&now),
+        )
+        .await
+        .unwrap();
+    assert_eq!(subscription_costs, costs);
+
     let mut now = OffsetDateTime::now_utc();
     now = now.add(Duration::from_secs(60 * 60 * 24));
     let then = now.sub(Duration::from_secs(60 * 60 * 24));
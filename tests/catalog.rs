@@ -0,0 +1,258 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the deserialization and rendering of catalogs.
+//!
+//! These tests do not require access to an Orb account.
+
+use std::collections::BTreeMap;
+
+use codes_iso_4217::CurrencyCode;
+use orb_billing::{
+    Amount, BillableMetric, Catalog, CatalogChange, Item, Plan, Price, PriceCadence,
+    PriceModelConfig, UnitConfig,
+};
+use serde_json::{json, Value};
+
+#[test]
+fn test_catalog() {
+    let catalog: Catalog = serde_json::from_str(
+        r#"{
+            "items": [{"name": "Compute"}],
+            "metrics": [{
+                "name": "Compute hours",
+                "item": "Compute",
+                "sql": "SELECT sum(hours) FROM events"
+            }],
+            "plans": [{
+                "external_id": "standard",
+                "name": "Standard",
+                "currency": "USD",
+                "metadata": {"tier": "1"},
+                "prices": [{
+                    "name": "Compute hours",
+                    "item": "Compute",
+                    "cadence": "monthly",
+                    "billable_metric": "Compute hours",
+                    "model_type": "unit",
+                    "unit_config": {"unit_amount": "1.50"}
+                }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(catalog.items[0].name, "Compute");
+    assert_eq!(catalog.metrics[0].item, "Compute");
+    assert_eq!(catalog.metrics[0].description, None);
+    assert!(catalog.prices.is_empty());
+    assert!(!catalog.prune);
+    let plan = &catalog.plans[0];
+    assert_eq!(plan.currency, CurrencyCode::USD);
+    assert_eq!(plan.prices[0].cadence, PriceCadence::Monthly);
+    assert_eq!(
+        plan.prices[0].billable_metric.as_deref(),
+        Some("Compute hours")
+    );
+    assert_eq!(
+        plan.prices[0].model,
+        PriceModelConfig::Unit {
+            unit_config: UnitConfig {
+                unit_amount: Amount::new(150, 2),
+            },
+        }
+    );
+
+    let changes = [
+        CatalogChange::CreatePlan(plan.clone()),
+        CatalogChange::UpdateMetricMetadata {
+            id: "metric_1".into(),
+            name: "Compute hours".into(),
+            metadata: BTreeMap::from([("unit".into(), Some("hours".into()))]),
+        },
+        CatalogChange::UpdatePlanMetadata {
+            id: "plan_1".into(),
+            external_id: "standard".into(),
+            metadata: BTreeMap::from([("tier".into(), Some("2".into())), ("legacy".into(), None)]),
+        },
+        CatalogChange::ArchivePlan {
+            id: "plan_2".into(),
+            external_id: "old".into(),
+        },
+        CatalogChange::Conflict {
+            kind: "plan",
+            external_id: "standard".into(),
+            detail: "existing plan differs from catalog in prices".into(),
+        },
+    ];
+    let rendered: Vec<_> = changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        rendered,
+        [
+            r#"+ create plan "standard" ("Standard", 1 prices)"#,
+            r#"~ update metric "Compute hours" metadata: unit="hours""#,
+            r#"~ update plan "standard" metadata: -legacy tier="2""#,
+            r#"- archive plan "old" (manual)"#,
+            r#"! conflict in plan "standard": existing plan differs from catalog in prices"#,
+        ]
+    );
+}
+
+fn unit_price(id: &str, unit_amount: &str) -> Value {
+    json!({
+        "id": id,
+        "name": "Compute hours",
+        "item": {"id": "item_1", "name": "Compute"},
+        "billable_metric": {"id": "metric_1"},
+        "model_type": "unit",
+        "price_type": "usage_price",
+        "cadence": "monthly",
+        "currency": "USD",
+        "unit_config": {"unit_amount": unit_amount}
+    })
+}
+
+fn plan(id: &str, external_id: &str, status: &str, prices: Vec<Value>) -> Value {
+    json!({
+        "id": id,
+        "external_plan_id": external_id,
+        "name": "Standard",
+        "description": "",
+        "created_at": "2023-01-01T00:00:00Z",
+        "currency": "USD",
+        "status": status,
+        "metadata": {"tier": "1"},
+        "prices": prices
+    })
+}
+
+#[test]
+fn test_catalog_diff() {
+    let catalog_price = json!({
+        "name": "Compute hours",
+        "item": "Compute",
+        "cadence": "monthly",
+        "billable_metric": "Compute hours",
+        "model_type": "unit",
+        "unit_config": {"unit_amount": "1.50"}
+    });
+    let catalog_plan = |external_id: &str, prices: Vec<&Value>| {
+        json!({
+            "external_id": external_id,
+            "name": "Standard",
+            "currency": "USD",
+            "metadata": {"tier": "1"},
+            "prices": prices
+        })
+    };
+    let catalog: Catalog = serde_json::from_value(json!({
+        "items": [{"name": "Compute"}, {"name": "Storage"}],
+        "metrics": [{
+            "name": "Compute hours",
+            "item": "Compute",
+            "sql": "SELECT sum(hours) FROM events",
+            "metadata": {"unit": "hours"}
+        }],
+        "plans": [
+            catalog_plan("standard", vec![&catalog_price]),
+            catalog_plan("legacy", vec![&catalog_price]),
+            catalog_plan("doubled", vec![&catalog_price, &catalog_price]),
+            catalog_plan("new", vec![&catalog_price]),
+        ],
+        "prune": true
+    }))
+    .unwrap();
+
+    let items: Vec<Item> = serde_json::from_value(json!([{
+        "id": "item_1",
+        "name": "Compute",
+        "created_at": "2023-01-01T00:00:00Z"
+    }]))
+    .unwrap();
+    let metrics: Vec<BillableMetric> = serde_json::from_value(json!([{
+        "id": "metric_1",
+        "name": "Compute hours",
+        "description": null,
+        "status": "active",
+        "item": items[0]
+    }]))
+    .unwrap();
+    let plans: Vec<Plan> = serde_json::from_value(json!([
+        // Amounts that differ only in trailing zeros are equal.
+        plan(
+            "plan_1",
+            "standard",
+            "active",
+            vec![unit_price("price_1", "1.5")]
+        ),
+        // Archived plans retain their external ID.
+        plan(
+            "plan_2",
+            "legacy",
+            "archived",
+            vec![unit_price("price_2", "1.50")]
+        ),
+        // Duplicate prices are not merged.
+        plan(
+            "plan_3",
+            "doubled",
+            "active",
+            vec![unit_price("price_3", "1.50")]
+        ),
+        plan("plan_4", "old", "active", vec![]),
+        plan("plan_5", "retired", "archived", vec![]),
+    ]))
+    .unwrap();
+    let prices: Vec<Price> = vec![];
+
+    let changeset = catalog.diff(&items, &metrics, &prices, &plans);
+    let new_plan = catalog.plans[3].clone();
+    assert_eq!(
+        changeset.changes,
+        [
+            CatalogChange::CreateItem(catalog.items[1].clone()),
+            CatalogChange::UpdateMetricMetadata {
+                id: "metric_1".into(),
+                name: "Compute hours".into(),
+                metadata: BTreeMap::from([("unit".into(), Some("hours".into()))]),
+            },
+            CatalogChange::Conflict {
+                kind: "plan",
+                external_id: "legacy".into(),
+                detail: "existing plan has status archived".into(),
+            },
+            CatalogChange::Conflict {
+                kind: "plan",
+                external_id: "doubled".into(),
+                detail: "existing plan differs from catalog in prices".into(),
+            },
+            CatalogChange::CreatePlan(new_plan),
+            CatalogChange::ArchivePlan {
+                id: "plan_4".into(),
+                external_id: "old".into(),
+            },
+        ]
+    );
+
+    // An account that matches the catalog requires no changes.
+    let catalog = Catalog {
+        items: catalog.items[..1].to_vec(),
+        plans: catalog.plans[..1].to_vec(),
+        ..catalog
+    };
+    let mut metrics = metrics;
+    metrics[0].metadata = BTreeMap::from([("unit".into(), "hours".into())]);
+    let changeset = catalog.diff(&items, &metrics, &prices, &plans[..1]);
+    assert_eq!(changeset.changes, []);
+}
@@ -0,0 +1,93 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for customer requests.
+//!
+//! These tests do not require access to an Orb account.
+
+use std::collections::BTreeMap;
+
+use orb_billing::{
+    CreateCustomerRequest, CustomerReportingConfiguration, CustomerTaxConfigurationRequest,
+    UpdateCustomerRequest,
+};
+use serde_json::json;
+
+#[test]
+fn test_customer_tax_configuration_request() {
+    let avalara = CustomerTaxConfigurationRequest::Avalara {
+        tax_exempt: true,
+        tax_exemption_code: Some("E"),
+    };
+    assert_eq!(
+        serde_json::to_value(&avalara).unwrap(),
+        json!({
+            "tax_provider": "avalara",
+            "tax_exempt": true,
+            "tax_exemption_code": "E",
+        })
+    );
+    let avalara = CustomerTaxConfigurationRequest::Avalara {
+        tax_exempt: false,
+        tax_exemption_code: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&avalara).unwrap(),
+        json!({"tax_provider": "avalara", "tax_exempt": false})
+    );
+    let taxjar = CustomerTaxConfigurationRequest::Taxjar { tax_exempt: true };
+    assert_eq!(
+        serde_json::to_value(&taxjar).unwrap(),
+        json!({"tax_provider": "taxjar", "tax_exempt": true})
+    );
+}
+
+#[test]
+fn test_customer_requests() {
+    let metadata = BTreeMap::from([("org_id".to_string(), "org-1".to_string())]);
+    let request = CreateCustomerRequest {
+        name: "Test",
+        email: "test@example.com",
+        auto_collection: Some(false),
+        reporting_configuration: Some(CustomerReportingConfiguration { exempt: true }),
+        tax_configuration: Some(CustomerTaxConfigurationRequest::Taxjar { tax_exempt: false }),
+        metadata: Some(&metadata),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "name": "Test",
+            "email": "test@example.com",
+            "auto_collection": false,
+            "reporting_configuration": {"exempt": true},
+            "tax_configuration": {"tax_provider": "taxjar", "tax_exempt": false},
+            "metadata": {"org_id": "org-1"},
+        })
+    );
+
+    // Metadata keys mapped to `None` are removed, and unset fields are
+    // omitted.
+    let metadata = BTreeMap::from([("org_id".to_string(), None)]);
+    let request = UpdateCustomerRequest {
+        email_delivery: Some(true),
+        metadata: Some(&metadata),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({"email_delivery": true, "metadata": {"org_id": null}})
+    );
+}
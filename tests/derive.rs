@@ -0,0 +1,129 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `OrbEvent` derive macro.
+//!
+//! These tests do not require access to an Orb account. Run them with
+//!
+//!     $ cargo test --features derive --test derive
+
+use std::collections::BTreeMap;
+
+use ::time::OffsetDateTime;
+use serde_json::Number;
+
+use orb_billing::{CustomerId, Error, Event, EventPropertyValue, OrbEvent};
+
+#[derive(Debug, Clone, PartialEq, OrbEvent)]
+#[orb(event_name = "compute_usage")]
+struct ComputeUsage {
+    cluster_id: String,
+    #[orb(rename = "credits")]
+    credits_used: f64,
+    replicas: u32,
+    managed: bool,
+    region: Option<String>,
+}
+
+#[test]
+fn test_orb_event_round_trip() {
+    let usage = ComputeUsage {
+        cluster_id: "u1".into(),
+        credits_used: 1.5,
+        replicas: 2,
+        managed: true,
+        region: None,
+    };
+    let properties = usage.to_properties();
+    assert_eq!(
+        properties,
+        BTreeMap::from([
+            ("cluster_id".into(), EventPropertyValue::String("u1".into())),
+            (
+                "credits".into(),
+                EventPropertyValue::Number(Number::from_f64(1.5).unwrap())
+            ),
+            ("replicas".into(), EventPropertyValue::Number(2.into())),
+            ("managed".into(), EventPropertyValue::Bool(true)),
+        ])
+    );
+    assert_eq!(ComputeUsage::from_properties(&properties).unwrap(), usage);
+
+    let timestamp = OffsetDateTime::now_utc();
+    let mut buf = BTreeMap::new();
+    let req = usage.to_ingest_request("key", CustomerId::External("org"), timestamp, &mut buf);
+    assert_eq!(req.event_name, "compute_usage");
+    assert_eq!(req.properties, &properties);
+
+    let event = Event {
+        id: "event".into(),
+        customer_id: "customer".into(),
+        external_customer_id: Some("org".into()),
+        event_name: "compute_usage".into(),
+        properties: properties.clone(),
+        timestamp,
+        deprecated: false,
+    };
+    assert_eq!(ComputeUsage::from_event(&event).unwrap(), usage);
+}
+
+#[test]
+fn test_orb_event_decode_errors() {
+    let mut properties = ComputeUsage {
+        cluster_id: "u1".into(),
+        credits_used: 1.0,
+        replicas: 1,
+        managed: false,
+        region: Some("us-east-1".into()),
+    }
+    .to_properties();
+
+    // Properties of the wrong type are rejected.
+    properties.insert("replicas".into(), EventPropertyValue::Number((-1).into()));
+    assert!(matches!(
+        ComputeUsage::from_properties(&properties),
+        Err(Error::UnexpectedResponse { .. })
+    ));
+
+    // Missing required properties are rejected.
+    properties.remove("replicas");
+    assert!(matches!(
+        ComputeUsage::from_properties(&properties),
+        Err(Error::UnexpectedResponse { .. })
+    ));
+
+    // Null properties decode as absent optional values.
+    properties.insert("region".into(), EventPropertyValue::Null);
+    properties.insert("replicas".into(), EventPropertyValue::Number(1.into()));
+    assert_eq!(
+        ComputeUsage::from_properties(&properties).unwrap().region,
+        None
+    );
+
+    // Events with a different name are rejected.
+    let event = Event {
+        id: "event".into(),
+        customer_id: "customer".into(),
+        external_customer_id: None,
+        event_name: "storage_usage".into(),
+        properties,
+        timestamp: OffsetDateTime::now_utc(),
+        deprecated: false,
+    };
+    assert!(matches!(
+        ComputeUsage::from_event(&event),
+        Err(Error::UnexpectedResponse { .. })
+    ));
+}
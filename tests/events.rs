@@ -0,0 +1,95 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the aggregation of events.
+//!
+//! These tests do not require access to an Orb account.
+
+use std::collections::BTreeMap;
+
+use orb_billing::{
+    Amount, Event, EventAggregate, EventAggregationGroup, EventAggregationKey, EventAggregator,
+    EventPropertyValue,
+};
+use serde_json::json;
+use time::OffsetDateTime;
+
+fn event(group: serde_json::Value, value: serde_json::Value) -> Event {
+    Event {
+        id: "event".into(),
+        customer_id: "customer".into(),
+        external_customer_id: None,
+        event_name: "test".into(),
+        properties: BTreeMap::from([
+            ("group".into(), serde_json::from_value(group).unwrap()),
+            ("value".into(), serde_json::from_value(value).unwrap()),
+        ]),
+        timestamp: OffsetDateTime::UNIX_EPOCH,
+        deprecated: false,
+    }
+}
+
+#[test]
+fn test_event_aggregator() {
+    let mut aggregator = EventAggregator::new()
+        .group_by("group")
+        .value_property("value");
+    aggregator.extend([
+        event(json!("1"), json!(0.1)),
+        event(json!("1"), json!(0.2)),
+        event(json!(1), json!(2)),
+        event(json!(1.0), json!("not a number")),
+        event(json!(true), json!(-1.5)),
+    ]);
+    let key = |group| EventAggregationKey {
+        event_name: "test".into(),
+        customer_id: "customer".into(),
+        group: Some(group),
+    };
+    assert_eq!(
+        aggregator.into_groups(),
+        BTreeMap::from([
+            (
+                key(EventAggregationGroup::String("1".into())),
+                EventAggregate {
+                    count: 2,
+                    sum: Amount::new(3, 1),
+                    max: Some(Amount::new(2, 1)),
+                }
+            ),
+            (
+                key(EventAggregationGroup::Number(Amount::from(1))),
+                EventAggregate {
+                    count: 2,
+                    sum: Amount::from(2),
+                    max: Some(Amount::from(2)),
+                }
+            ),
+            (
+                key(EventAggregationGroup::Bool(true)),
+                EventAggregate {
+                    count: 1,
+                    sum: Amount::new(-15, 1),
+                    max: Some(Amount::new(-15, 1)),
+                }
+            ),
+        ])
+    );
+
+    assert_eq!(
+        EventAggregationGroup::from(&EventPropertyValue::Null),
+        EventAggregationGroup::Other("null".into())
+    );
+}
@@ -0,0 +1,93 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `Amount` and `Money` types.
+//!
+//! These tests do not require access to an Orb account.
+
+use codes_iso_4217::CurrencyCode;
+use orb_billing::{Amount, BpsConfig, Money};
+use serde_json::json;
+
+#[test]
+fn test_amount() {
+    // Amounts preserve their scale, but compare by value.
+    let a: Amount = "1.50".parse().unwrap();
+    assert_eq!(a.to_string(), "1.50");
+    assert_eq!(a, Amount::new(15, 1));
+    assert_eq!(a.normalize().to_string(), "1.5");
+    assert_eq!(format!("{a:.3}"), "1.500");
+
+    // Arithmetic is exact.
+    let b: Amount = "0.1".parse().unwrap();
+    let c: Amount = "0.2".parse().unwrap();
+    assert_eq!(b + c, "0.3".parse().unwrap());
+    assert_eq!((a - Amount::from(2)).to_string(), "-0.50");
+    assert!((a - Amount::from(2)).is_negative());
+    assert_eq!(a * Amount::from(3), "4.5".parse().unwrap());
+    assert_eq!([a, b, c].iter().sum::<Amount>(), "1.8".parse().unwrap());
+    assert_eq!(Amount::new(1, 0).checked_div(Amount::ZERO), None);
+    assert_eq!(Amount::new(12345, 3).round_dp(2).to_string(), "12.35");
+    assert_eq!(Amount::new(-12345, 3).round_dp(2).to_string(), "-12.35");
+
+    // Amounts deserialize from strings and numbers, and serialize as strings.
+    let amounts: Vec<Amount> = serde_json::from_value(json!(["1.50", 42, 0.1, "1e3"])).unwrap();
+    assert_eq!(amounts, [a, Amount::from(42), b, Amount::from(1000)],);
+    assert_eq!(serde_json::to_value(a).unwrap(), json!("1.50"));
+    assert!(serde_json::from_value::<Amount>(json!("one")).is_err());
+    assert!(serde_json::from_value::<Amount>(json!(null)).is_err());
+
+    assert_eq!(Amount::try_from(0.25).unwrap(), Amount::new(25, 2));
+    assert!(Amount::try_from(f64::NAN).is_err());
+    assert_eq!(Amount::new(25, 2).to_f64(), 0.25);
+
+    // Amounts in fields where Orb requires a number serialize as numbers, but
+    // only if they can be represented exactly.
+    let bps = |bps| BpsConfig {
+        bps,
+        per_unit_maximum: None,
+    };
+    assert_eq!(
+        serde_json::to_value(bps(Amount::new(25, 2))).unwrap(),
+        json!({"bps": 0.25, "per_unit_maximum": null})
+    );
+    assert_eq!(
+        serde_json::to_value(bps(Amount::from(5))).unwrap(),
+        json!({"bps": 5, "per_unit_maximum": null})
+    );
+    let inexact: Amount = "0.1000000000000000000001".parse().unwrap();
+    assert!(serde_json::to_value(bps(inexact)).is_err());
+}
+
+#[test]
+fn test_money() {
+    let usd = |s: &str| Money::new(s.parse().unwrap(), CurrencyCode::USD);
+    assert_eq!(usd("1.50").to_string(), "1.50 USD");
+    assert_eq!(usd("1.005").round(), usd("1.01"));
+    assert_eq!(
+        Money::new(Amount::new(1005, 1), CurrencyCode::JPY).round(),
+        Money::new(Amount::from(101), CurrencyCode::JPY)
+    );
+    assert_eq!(usd("1.50").checked_add(usd("2.25")), Some(usd("3.75")));
+    assert_eq!(usd("1.50").checked_sub(usd("2.25")), Some(usd("-0.75")));
+    assert_eq!(usd("1.50").checked_mul(Amount::from(2)), Some(usd("3")));
+    let eur = Money::zero(CurrencyCode::EUR);
+    assert_eq!(usd("1.50").checked_add(eur), None);
+    assert_eq!(
+        Money::from_code(Amount::from(1), "EUR"),
+        Some(Money::new(Amount::from(1), CurrencyCode::EUR))
+    );
+    assert_eq!(Money::from_code(Amount::from(1), "credits"), None);
+}
@@ -0,0 +1,199 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the deserialization of prices.
+//!
+//! These tests do not require access to an Orb account.
+
+use orb_billing::{
+    Amount, GroupedTier, GroupedTieredConfig, Price, ThresholdTotalAmountConfig,
+    ThresholdTotalAmountTier, Tier, TieredPackageConfig, TieredPackageTier,
+};
+
+#[test]
+fn test_price_deserialization() {
+    let price: Price = serde_json::from_str(
+        r#"{
+            "id": "price_1",
+            "external_price_id": null,
+            "name": "Compute",
+            "item": {"id": "item_1", "name": "Compute"},
+            "model_type": "tiered",
+            "price_type": "usage_price",
+            "cadence": "monthly",
+            "currency": "USD",
+            "tiered_config": {
+                "tiers": [
+                    {"first_unit": 0, "last_unit": 10, "unit_amount": "1.00"},
+                    {"first_unit": 10, "last_unit": null, "unit_amount": "0.50"}
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+    let Price::Tiered(tiered) = &price else {
+        panic!("expected tiered price, got {price:?}");
+    };
+    assert_eq!(price.id(), Some("price_1"));
+    assert_eq!(tiered.base.name.as_deref(), Some("Compute"));
+    assert_eq!(
+        tiered.tiered_config.tiers[1],
+        Tier {
+            first_unit: 10.into(),
+            last_unit: None,
+            unit_amount: Amount::new(50, 2),
+        }
+    );
+
+    // Prices with unknown models are preserved.
+    let price: Price = serde_json::from_str(
+        r#"{
+            "id": "price_2",
+            "external_price_id": "ext",
+            "model_type": "some_future_model",
+            "some_future_model_config": {}
+        }"#,
+    )
+    .unwrap();
+    assert!(matches!(price, Price::Other(_)));
+    assert_eq!(price.id(), Some("price_2"));
+    assert_eq!(price.external_id(), Some("ext"));
+}
+
+#[test]
+fn test_threshold_total_amount_price_deserialization() {
+    let price: Price = serde_json::from_str(
+        r#"{
+            "id": "price_1",
+            "name": "Threshold",
+            "item": {"id": "item_1", "name": "Compute"},
+            "model_type": "threshold_total_amount",
+            "price_type": "usage_price",
+            "cadence": "monthly",
+            "currency": "USD",
+            "threshold_total_amount_config": {
+                "consumption_table": [
+                    {"threshold": "10", "total_amount": "100.00"},
+                    {"threshold": 20, "total_amount": "150.00"}
+                ],
+                "prorate": true
+            }
+        }"#,
+    )
+    .unwrap();
+    let Price::ThresholdTotalAmount(price) = &price else {
+        panic!("expected threshold total amount price, got {price:?}");
+    };
+    assert_eq!(
+        price.threshold_total_amount_config,
+        ThresholdTotalAmountConfig {
+            consumption_table: vec![
+                ThresholdTotalAmountTier {
+                    threshold: 10.into(),
+                    total_amount: Amount::new(10000, 2),
+                },
+                ThresholdTotalAmountTier {
+                    threshold: 20.into(),
+                    total_amount: Amount::new(15000, 2),
+                },
+            ],
+            prorate: Some(true),
+        }
+    );
+}
+
+#[test]
+fn test_tiered_package_price_deserialization() {
+    let price: Price = serde_json::from_str(
+        r#"{
+            "id": "price_1",
+            "name": "Packages",
+            "item": {"id": "item_1", "name": "Compute"},
+            "model_type": "tiered_package",
+            "price_type": "usage_price",
+            "cadence": "monthly",
+            "currency": "USD",
+            "tiered_package_config": {
+                "package_size": 10,
+                "tiers": [
+                    {"tier_lower_bound": "0", "per_unit": "5.00"},
+                    {"tier_lower_bound": "100", "per_unit": "4.50"}
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+    let Price::TieredPackage(price) = &price else {
+        panic!("expected tiered package price, got {price:?}");
+    };
+    assert_eq!(
+        price.tiered_package_config,
+        TieredPackageConfig {
+            package_size: 10.into(),
+            tiers: vec![
+                TieredPackageTier {
+                    tier_lower_bound: Amount::ZERO,
+                    per_unit: Amount::new(500, 2),
+                },
+                TieredPackageTier {
+                    tier_lower_bound: 100.into(),
+                    per_unit: Amount::new(450, 2),
+                },
+            ],
+        }
+    );
+}
+
+#[test]
+fn test_grouped_tiered_price_deserialization() {
+    let price: Price = serde_json::from_str(
+        r#"{
+            "id": "price_1",
+            "name": "Grouped",
+            "item": {"id": "item_1", "name": "Compute"},
+            "model_type": "grouped_tiered",
+            "price_type": "usage_price",
+            "cadence": "monthly",
+            "currency": "USD",
+            "grouped_tiered_config": {
+                "grouping_key": "region",
+                "tiers": [
+                    {"tier_lower_bound": "0", "unit_amount": "1.00"},
+                    {"tier_lower_bound": "1000", "unit_amount": "0.75"}
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+    let Price::GroupedTiered(price) = &price else {
+        panic!("expected grouped tiered price, got {price:?}");
+    };
+    assert_eq!(
+        price.grouped_tiered_config,
+        GroupedTieredConfig {
+            grouping_key: "region".into(),
+            tiers: vec![
+                GroupedTier {
+                    tier_lower_bound: Amount::ZERO,
+                    unit_amount: Amount::new(100, 2),
+                },
+                GroupedTier {
+                    tier_lower_bound: 1000.into(),
+                    unit_amount: Amount::new(75, 2),
+                },
+            ],
+        }
+    );
+}
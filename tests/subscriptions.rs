@@ -0,0 +1,554 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for subscription requests and the subscription lifecycle.
+//!
+//! These tests do not require access to an Orb account.
+
+use std::collections::BTreeMap;
+
+use orb_billing::{
+    AddPriceIntervalRequest, Amount, BillingPeriod, CreateSubscriptionRequest, CustomerId,
+    EditPriceIntervalRequest, FixedFeeQuantityChangeOption, FixedFeeQuantityTransitionRequest,
+    LifecycleStatus, PlanId, PriceCadence, PriceId, PriceIntervalDate, PriceIntervalsRequest,
+    PriceModelConfig, PriceOverrideDiscountRequest, PriceOverrideRequest, RedeemCouponRequest,
+    Subscription, SubscriptionChangeOption, SubscriptionStatus, TrialEndDate, UnitConfig,
+    UpdateFixedFeeQuantityRequest, UpdateTrialRequest,
+};
+use serde_json::{json, Value};
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Month, OffsetDateTime};
+
+#[test]
+fn test_create_subscription_request_serialization() {
+    let metadata = BTreeMap::from([("deal".to_string(), "enterprise".to_string())]);
+    let req = CreateSubscriptionRequest {
+        customer_id: CustomerId::External("acme"),
+        plan_id: PlanId::External("enterprise"),
+        // Dates are converted to UTC.
+        start_date: Some(datetime("2024-03-01T00:00:00-05:00")),
+        end_date: Some(datetime("2025-03-01T00:00:00+01:00")),
+        initial_phase_order: Some(2),
+        trial_duration_days: Some(14),
+        invoicing_threshold: Some(Amount::new(100000, 2)),
+        metadata: Some(&metadata),
+        price_overrides: vec![
+            PriceOverrideRequest {
+                price_id: "price_1",
+                model: PriceModelConfig::Unit {
+                    unit_config: UnitConfig {
+                        unit_amount: Amount::new(75, 2),
+                    },
+                },
+                fixed_price_quantity: None,
+                discount: Some(PriceOverrideDiscountRequest::Percentage {
+                    percentage_discount: Amount::new(1, 1),
+                }),
+                minimum_amount: Some(Amount::from(10)),
+                maximum_amount: None,
+            },
+            PriceOverrideRequest {
+                price_id: "price_2",
+                model: PriceModelConfig::Unit {
+                    unit_config: UnitConfig {
+                        unit_amount: Amount::from(5),
+                    },
+                },
+                fixed_price_quantity: Some(Amount::from(20)),
+                discount: None,
+                minimum_amount: None,
+                maximum_amount: Some(Amount::from(500)),
+            },
+        ],
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&req).unwrap(),
+        json!({
+            "external_customer_id": "acme",
+            "external_plan_id": "enterprise",
+            "start_date": "2024-03-01T05:00:00Z",
+            "end_date": "2025-02-28T23:00:00Z",
+            "initial_phase_order": 2,
+            "trial_duration_days": 14,
+            "invoicing_threshold": "1000.00",
+            "metadata": {"deal": "enterprise"},
+            "price_overrides": [
+                {
+                    "id": "price_1",
+                    "model_type": "unit",
+                    "unit_config": {"unit_amount": "0.75"},
+                    "discount": {
+                        "discount_type": "percentage",
+                        "percentage_discount": 0.1,
+                    },
+                    "minimum_amount": "10",
+                },
+                {
+                    "id": "price_2",
+                    "model_type": "unit",
+                    "unit_config": {"unit_amount": "5"},
+                    "fixed_price_quantity": 20,
+                    "maximum_amount": "500",
+                },
+            ],
+        })
+    );
+
+    // Omitted overrides are not serialized.
+    let req = CreateSubscriptionRequest {
+        customer_id: CustomerId::Orb("cus_1"),
+        plan_id: PlanId::Orb("plan_1"),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&req).unwrap(),
+        json!({"customer_id": "cus_1", "plan_id": "plan_1"})
+    );
+}
+
+#[test]
+fn test_subscription_change_request_serialization() {
+    // Dates are converted to UTC.
+    let req = RedeemCouponRequest {
+        coupon_id: "coupon_1",
+        change_option: SubscriptionChangeOption::RequestedDate,
+        change_date: Some(datetime("2024-03-01T00:00:00-05:00")),
+    };
+    assert_eq!(
+        serde_json::to_value(req).unwrap(),
+        json!({
+            "coupon_id": "coupon_1",
+            "change_option": "requested_date",
+            "change_date": "2024-03-01T05:00:00Z",
+        })
+    );
+
+    let req = UpdateTrialRequest {
+        trial_end_date: TrialEndDate::Date(datetime("2024-03-01T00:00:00-05:00")),
+        shift: Some(false),
+    };
+    assert_eq!(
+        serde_json::to_value(req).unwrap(),
+        json!({"trial_end_date": "2024-03-01T05:00:00Z", "shift": false})
+    );
+    let req = UpdateTrialRequest {
+        trial_end_date: TrialEndDate::Immediate,
+        shift: None,
+    };
+    assert_eq!(
+        serde_json::to_value(req).unwrap(),
+        json!({"trial_end_date": "immediate"})
+    );
+}
+
+#[test]
+fn test_price_interval_request_serialization() {
+    // Datetimes are converted to UTC.
+    let transitions = [FixedFeeQuantityTransitionRequest {
+        effective_date: datetime("2024-03-01T00:00:00-05:00"),
+        quantity: Amount::from(5),
+    }];
+    let add = [AddPriceIntervalRequest {
+        price_id: PriceId::External("seats"),
+        start_date: PriceIntervalDate::Date(datetime("2024-03-01T00:00:00+01:00")),
+        end_date: Some(PriceIntervalDate::EndOfTerm),
+        fixed_fee_quantity_transitions: Some(&transitions),
+    }];
+    let edit = [EditPriceIntervalRequest {
+        price_interval_id: "interval_1",
+        end_date: Some(PriceIntervalDate::StartOfTerm),
+        ..Default::default()
+    }];
+    let req = PriceIntervalsRequest {
+        add: &add,
+        edit: &edit,
+    };
+    assert_eq!(
+        serde_json::to_value(req).unwrap(),
+        json!({
+            "add": [{
+                "external_price_id": "seats",
+                "start_date": "2024-02-29T23:00:00Z",
+                "end_date": "end_of_term",
+                "fixed_fee_quantity_transitions": [{
+                    "effective_date": "2024-03-01T05:00:00Z",
+                    "quantity": 5,
+                }],
+            }],
+            "edit": [{"price_interval_id": "interval_1", "end_date": "start_of_term"}],
+        })
+    );
+
+    // Fixed fee quantity changes take a calendar date.
+    let req = UpdateFixedFeeQuantityRequest {
+        price_id: "price_1",
+        quantity: Amount::from(3),
+        effective_date: Some(Date::from_calendar_date(2024, Month::March, 1).unwrap()),
+        change_option: Some(FixedFeeQuantityChangeOption::EffectiveDate),
+    };
+    assert_eq!(
+        serde_json::to_value(req).unwrap(),
+        json!({
+            "price_id": "price_1",
+            "quantity": 3,
+            "effective_date": "2024-03-01",
+            "change_option": "effective_date",
+        })
+    );
+}
+
+fn datetime(s: &str) -> OffsetDateTime {
+    OffsetDateTime::parse(s, &Rfc3339).unwrap()
+}
+
+fn price(id: &str, cadence: &str) -> Value {
+    json!({
+        "id": id,
+        "external_price_id": null,
+        "item": {"id": "item_1", "name": "Seats"},
+        "model_type": "unit",
+        "cadence": cadence,
+        "unit_config": {"unit_amount": "10.00"},
+    })
+}
+
+/// Returns a fixture subscription that started on 2024-01-15, is billed
+/// monthly, and has no trial or end date, with `overrides` applied.
+fn subscription(overrides: Value) -> Subscription<Value> {
+    let mut value = json!({
+        "id": "sub_1",
+        "customer": {"id": "cus_1"},
+        "plan": {
+            "id": "plan_1",
+            "external_plan_id": null,
+            "name": "Team",
+            "description": "",
+            "created_at": "2024-01-01T00:00:00Z",
+            "base_plan_id": null,
+            "prices": [price("price_1", "monthly"), price("price_2", "annual")],
+        },
+        "start_date": "2024-01-15T00:00:00Z",
+        "end_date": null,
+        "status": "active",
+        "current_billing_period_start_date": "2024-03-15T00:00:00Z",
+        "current_billing_period_end_date": "2024-04-15T00:00:00Z",
+        "active_plan_phase_order": null,
+        "fixed_fee_quantity_schedule": [],
+        "net_terms": 30,
+        "auto_collection": true,
+        "default_invoice_memo": "",
+        "created_at": "2024-01-10T00:00:00Z",
+    });
+    for (key, override_value) in overrides.as_object().unwrap() {
+        match key.as_str() {
+            "plan" => {
+                for (key, override_value) in override_value.as_object().unwrap() {
+                    value["plan"][key] = override_value.clone();
+                }
+            }
+            _ => value[key] = override_value.clone(),
+        }
+    }
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_subscription_status_at() {
+    let before_start = datetime("2024-01-14T23:59:59Z");
+    let start = datetime("2024-01-15T00:00:00Z");
+    let during_trial = datetime("2024-01-20T00:00:00Z");
+    let trial_end = datetime("2024-01-29T00:00:00Z");
+    let before_end = datetime("2024-06-14T23:59:59Z");
+    let end = datetime("2024-06-15T00:00:00Z");
+    let after_end = datetime("2030-01-01T00:00:00Z");
+
+    // A subscription without a trial or end date.
+    let sub = subscription(json!({}));
+    assert_eq!(sub.status_at(before_start), LifecycleStatus::Upcoming);
+    assert_eq!(sub.status_at(start), LifecycleStatus::Active);
+    assert_eq!(sub.status_at(after_end), LifecycleStatus::Active);
+
+    // A subscription with a trial.
+    let sub = subscription(json!({"trial_info": {"end_date": "2024-01-29T00:00:00Z"}}));
+    assert_eq!(sub.status_at(before_start), LifecycleStatus::Upcoming);
+    assert_eq!(sub.status_at(start), LifecycleStatus::Trialing);
+    assert_eq!(sub.status_at(during_trial), LifecycleStatus::Trialing);
+    assert_eq!(sub.status_at(trial_end), LifecycleStatus::Active);
+    assert_eq!(sub.status_at(after_end), LifecycleStatus::Active);
+
+    // A subscription with an end date.
+    let sub = subscription(json!({"end_date": "2024-06-15T00:00:00Z"}));
+    assert_eq!(sub.status_at(before_start), LifecycleStatus::Upcoming);
+    assert_eq!(sub.status_at(start), LifecycleStatus::ScheduledToEnd);
+    assert_eq!(sub.status_at(before_end), LifecycleStatus::ScheduledToEnd);
+    assert_eq!(sub.status_at(end), LifecycleStatus::Ended);
+    assert_eq!(sub.status_at(after_end), LifecycleStatus::Ended);
+
+    // A fixed-term subscription, which Orb reports as active rather than
+    // cancelled, is scheduled to end.
+    let sub = subscription(json!({
+        "status": "active",
+        "start_date": "2024-01-15T00:00:00Z",
+        "end_date": "2025-01-15T00:00:00Z",
+    }));
+    assert_eq!(sub.status, Some(SubscriptionStatus::Active));
+    assert_eq!(sub.status_at(start), LifecycleStatus::ScheduledToEnd);
+    assert!(sub.status_at(start).is_entitled());
+    assert_eq!(
+        sub.status_at(datetime("2025-01-14T23:59:59Z")),
+        LifecycleStatus::ScheduledToEnd
+    );
+    assert_eq!(
+        sub.status_at(datetime("2025-01-15T00:00:00Z")),
+        LifecycleStatus::Ended
+    );
+    assert!(!sub
+        .status_at(datetime("2025-01-15T00:00:00Z"))
+        .is_entitled());
+
+    // A subscription with a trial and an end date.
+    let sub = subscription(json!({
+        "end_date": "2024-06-15T00:00:00Z",
+        "trial_info": {"end_date": "2024-01-29T00:00:00Z"},
+    }));
+    assert_eq!(sub.status_at(before_start), LifecycleStatus::Upcoming);
+    assert_eq!(sub.status_at(during_trial), LifecycleStatus::Trialing);
+    assert_eq!(sub.status_at(trial_end), LifecycleStatus::ScheduledToEnd);
+    assert_eq!(sub.status_at(end), LifecycleStatus::Ended);
+
+    // A subscription that ends during its trial.
+    let sub = subscription(json!({
+        "end_date": "2024-01-20T00:00:00Z",
+        "trial_info": {"end_date": "2024-01-29T00:00:00Z"},
+    }));
+    assert_eq!(sub.status_at(start), LifecycleStatus::Trialing);
+    assert_eq!(sub.status_at(during_trial), LifecycleStatus::Ended);
+
+    // A subscription whose status is computed in another timezone.
+    let sub = subscription(json!({"start_date": "2024-01-15T00:00:00-05:00"}));
+    assert_eq!(
+        sub.status_at(datetime("2024-01-15T04:59:59Z")),
+        LifecycleStatus::Upcoming
+    );
+    assert_eq!(
+        sub.status_at(datetime("2024-01-15T05:00:00Z")),
+        LifecycleStatus::Active
+    );
+
+    assert!(!LifecycleStatus::Upcoming.is_entitled());
+    assert!(LifecycleStatus::Trialing.is_entitled());
+    assert!(LifecycleStatus::Active.is_entitled());
+    assert!(LifecycleStatus::ScheduledToEnd.is_entitled());
+    assert!(!LifecycleStatus::Ended.is_entitled());
+}
+
+#[test]
+fn test_subscription_billing_periods() {
+    let period = |start, end| BillingPeriod {
+        start: datetime(start),
+        end: datetime(end),
+    };
+
+    // The next period follows the current period by the plan's shortest
+    // cadence.
+    let sub = subscription(json!({}));
+    let current = period("2024-03-15T00:00:00Z", "2024-04-15T00:00:00Z");
+    assert_eq!(sub.plan.billing_cadence(), Some(PriceCadence::Monthly));
+    assert_eq!(sub.current_billing_period(), Some(current));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-04-15T00:00:00Z", "2024-05-15T00:00:00Z"))
+    );
+    assert!(current.contains(datetime("2024-03-15T00:00:00Z")));
+    assert!(current.contains(datetime("2024-04-14T23:59:59Z")));
+    assert!(!current.contains(datetime("2024-04-15T00:00:00Z")));
+    assert!(!current.contains(datetime("2024-03-14T23:59:59Z")));
+
+    // The next period is cut short by the subscription's end date.
+    let sub = subscription(json!({"end_date": "2024-05-01T00:00:00Z"}));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-04-15T00:00:00Z", "2024-05-01T00:00:00Z"))
+    );
+
+    // There is no next period if the subscription ends with the current
+    // period.
+    let sub = subscription(json!({"end_date": "2024-04-15T00:00:00Z"}));
+    assert_eq!(sub.next_billing_period(), None);
+
+    // There are no periods if the subscription is not active.
+    let sub = subscription(json!({
+        "current_billing_period_start_date": null,
+        "current_billing_period_end_date": null,
+    }));
+    assert_eq!(sub.current_billing_period(), None);
+    assert_eq!(sub.next_billing_period(), None);
+
+    // Quarterly, semi-annual and annual cadences.
+    for (cadence, expected_cadence, end) in [
+        ("quarterly", PriceCadence::Quarterly, "2024-07-15T00:00:00Z"),
+        (
+            "semi_annual",
+            PriceCadence::SemiAnnual,
+            "2024-10-15T00:00:00Z",
+        ),
+        ("annual", PriceCadence::Annual, "2025-04-15T00:00:00Z"),
+    ] {
+        let sub = subscription(json!({
+            "plan": {"prices": [price("price_1", "one_time"), price("price_2", cadence)]},
+        }));
+        assert_eq!(sub.plan.billing_cadence(), Some(expected_cadence));
+        assert_eq!(
+            sub.next_billing_period(),
+            Some(period("2024-04-15T00:00:00Z", end))
+        );
+    }
+
+    // Days past the end of the next period's final month are clamped.
+    let sub = subscription(json!({
+        "current_billing_period_start_date": "2023-12-31T00:00:00Z",
+        "current_billing_period_end_date": "2024-01-31T00:00:00Z",
+    }));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-01-31T00:00:00Z", "2024-02-29T00:00:00Z"))
+    );
+    let sub = subscription(json!({
+        "plan": {"prices": [price("price_1", "annual")]},
+        "current_billing_period_start_date": "2023-02-28T00:00:00Z",
+        "current_billing_period_end_date": "2024-02-29T00:00:00Z",
+    }));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-02-29T00:00:00Z", "2025-02-28T00:00:00Z"))
+    );
+
+    // Clamping does not carry forward into the following periods.
+    let sub = subscription(json!({
+        "current_billing_period_start_date": "2024-01-31T00:00:00Z",
+        "current_billing_period_end_date": "2024-02-29T00:00:00Z",
+    }));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-02-29T00:00:00Z", "2024-03-31T00:00:00Z"))
+    );
+    let sub = subscription(json!({
+        "current_billing_period_start_date": "2024-02-29T00:00:00Z",
+        "current_billing_period_end_date": "2024-03-31T00:00:00Z",
+    }));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-03-31T00:00:00Z", "2024-04-30T00:00:00Z"))
+    );
+
+    // A period that is cut short to align with the billing cycle day is
+    // followed by a full period.
+    let sub = subscription(json!({
+        "current_billing_period_start_date": "2024-01-20T00:00:00Z",
+        "current_billing_period_end_date": "2024-02-01T00:00:00Z",
+    }));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-02-01T00:00:00Z", "2024-03-01T00:00:00Z"))
+    );
+
+    // The billing cycle day reported in price intervals takes precedence
+    // over the day inferred from the current period.
+    let sub = subscription(json!({
+        "current_billing_period_start_date": "2024-02-10T00:00:00Z",
+        "current_billing_period_end_date": "2024-02-29T00:00:00Z",
+        "price_intervals": [
+            {
+                "id": "interval_1",
+                "price": price("price_1", "monthly"),
+                "start_date": "2023-12-01T00:00:00Z",
+                "end_date": "2024-02-10T00:00:00Z",
+                "billing_cycle_day": 1,
+            },
+            {
+                "id": "interval_2",
+                "price": price("price_1", "monthly"),
+                "start_date": "2024-02-10T00:00:00Z",
+                "end_date": null,
+                "billing_cycle_day": 31,
+            },
+        ],
+    }));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-02-29T00:00:00Z", "2024-03-31T00:00:00Z"))
+    );
+
+    // Periods that cross a year boundary.
+    let sub = subscription(json!({
+        "current_billing_period_start_date": "2024-11-15T00:00:00Z",
+        "current_billing_period_end_date": "2024-12-15T00:00:00Z",
+    }));
+    assert_eq!(
+        sub.next_billing_period(),
+        Some(period("2024-12-15T00:00:00Z", "2025-01-15T00:00:00Z"))
+    );
+
+    // Offsets are preserved.
+    let sub = subscription(json!({
+        "current_billing_period_start_date": "2024-03-01T00:00:00-05:00",
+        "current_billing_period_end_date": "2024-04-01T00:00:00-04:00",
+    }));
+    let next = sub.next_billing_period().unwrap();
+    assert_eq!(next.end, datetime("2024-05-01T00:00:00-04:00"));
+
+    // There is no next period if the plan has no recurring prices.
+    let sub = subscription(json!({"plan": {"prices": [price("price_1", "one_time")]}}));
+    assert_eq!(sub.plan.billing_cadence(), None);
+    assert_eq!(sub.next_billing_period(), None);
+    let sub = subscription(json!({"plan": {"prices": []}}));
+    assert_eq!(sub.plan.billing_cadence(), None);
+    assert_eq!(sub.next_billing_period(), None);
+}
+
+#[test]
+fn test_subscription_active_plan_phase() {
+    let phases = json!([
+        {"id": "phase_1", "name": "Ramp", "order": 1, "duration": 3, "duration_unit": "monthly"},
+        {"id": "phase_2", "name": "Steady", "order": 2, "duration": null},
+    ]);
+
+    let sub = subscription(json!({
+        "plan": {"plan_phases": phases},
+        "active_plan_phase_order": 2,
+    }));
+    assert_eq!(sub.active_plan_phase().map(|p| &*p.name), Some("Steady"));
+
+    let sub = subscription(json!({
+        "plan": {"plan_phases": phases},
+        "active_plan_phase_order": 1,
+    }));
+    assert_eq!(sub.active_plan_phase().map(|p| &*p.name), Some("Ramp"));
+
+    // The active phase is unknown if Orb does not report it or if the
+    // reported phase does not exist.
+    let sub = subscription(json!({"plan": {"plan_phases": phases}}));
+    assert_eq!(sub.active_plan_phase(), None);
+    let sub = subscription(json!({
+        "plan": {"plan_phases": phases},
+        "active_plan_phase_order": 3,
+    }));
+    assert_eq!(sub.active_plan_phase(), None);
+
+    // Plans without phases have no active phase.
+    let sub = subscription(json!({"active_plan_phase_order": 1}));
+    assert_eq!(sub.active_plan_phase(), None);
+}